@@ -0,0 +1,86 @@
+//! Benchmarks `Fit::augment()` against parsing,
+//! for a synthetic FIT file with 200 000 `record` messages.
+//! `fields` only visits each data field,
+//! which is the least `augment` can cost.
+//!
+//! ```sh
+//! cargo bench --bench augment
+//! ```
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use fit_rs::{crc16, Fit};
+
+const RECORDS: u32 = 200_000;
+
+/// FIT file with a `record/20` definition message followed by
+/// `RECORDS` data messages with timestamp, position, altitude,
+/// heart rate, distance, and speed.
+fn fit_bytes() -> Vec<u8> {
+    // Definition message, local ID 0, little endian
+    let mut data: Vec<u8> = vec![0x40, 0, 0];
+    data.extend(20_u16.to_le_bytes());
+    let fields: [(u8, u8, u8); 7] = [
+        (253, 4, 0x86), // timestamp, uint32
+        (0, 4, 0x85),   // position_lat, sint32
+        (1, 4, 0x85),   // position_long, sint32
+        (2, 2, 0x84),   // altitude, uint16
+        (3, 1, 0x02),   // heart_rate, uint8
+        (5, 4, 0x86),   // distance, uint32
+        (6, 2, 0x84),   // speed, uint16
+    ];
+    data.push(fields.len() as u8);
+    for (field_def_no, size, base_type) in fields {
+        data.extend([field_def_no, size, base_type]);
+    }
+
+    for i in 0 .. RECORDS {
+        data.push(0x00);
+        data.extend((1_000_000_000 + i).to_le_bytes());
+        data.extend((715_827_882 + i as i32).to_le_bytes());
+        data.extend((143_165_576 + i as i32).to_le_bytes());
+        data.extend((2_500 + (i % 100) as u16).to_le_bytes());
+        data.push(120 + (i % 50) as u8);
+        data.extend((i * 250).to_le_bytes());
+        data.extend((2_500 + (i % 500) as u16).to_le_bytes());
+    }
+
+    let mut fit: Vec<u8> = vec![14, 0x20];
+    fit.extend(2_195_u16.to_le_bytes());
+    fit.extend((data.len() as u32).to_le_bytes());
+    fit.extend(b".FIT");
+    fit.extend(crc16(&fit).to_le_bytes());
+    fit.extend(data);
+    fit.extend(crc16(&fit).to_le_bytes());
+    fit
+}
+
+fn augment(c: &mut Criterion) {
+    let bytes = fit_bytes();
+    let fit = Fit::from_reader(bytes.as_slice()).expect("Failed to parse FIT data");
+
+    let mut group = c.benchmark_group("augment");
+    group.sample_size(10);
+    group.bench_function("parse", |b| b.iter(|| {
+        Fit::from_reader(bytes.as_slice()).expect("Failed to parse FIT data")
+    }));
+    group.bench_function("fields", |b| b.iter_batched_ref(
+        || fit.clone(),
+        |fit| fit.records.iter_mut()
+            .flat_map(|m| m.fields.iter_mut())
+            .for_each(|f| {
+                black_box(f.field_def_no());
+            }),
+        BatchSize::LargeInput
+    ));
+    group.bench_function("augment", |b| b.iter_batched_ref(
+        || fit.clone(),
+        |fit| fit.augment(),
+        BatchSize::LargeInput
+    ));
+    group.finish();
+}
+
+criterion_group!(benches, augment);
+criterion_main!(benches);
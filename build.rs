@@ -0,0 +1,626 @@
+//! Generates FIT profile tables from the [FIT SDK](https://developer.garmin.com/fit/overview/) Profile.xlsx,
+//! exported as CSV:
+//! - `profile/types.csv`: "Types" sheet.
+//! - `profile/messages.csv`: "Messages" sheet.
+//!
+//! Upgrading to a new FIT SDK release means exporting the two sheets
+//! from the new Profile.xlsx as CSV, replacing the files in `profile/`.
+//! Values that can not be represented in the generated profile,
+//! e.g. unknown field types, are left out with a `cargo:warning`,
+//! so that a new SDK release does not fail the build.
+//!
+//! Writes `types.rs` and `messages.rs` to `OUT_DIR`, which are included
+//! in `src/profile/types.rs` and `src/profile/profile.rs` respectively.
+
+use std::{env, fmt::Write, fs, path::Path};
+
+const TYPES_CSV: &str = "profile/types.csv";
+const MESSAGES_CSV: &str = "profile/messages.csv";
+
+/// Enum names for types in Profile.xlsx, as `(type name, enum name)`,
+/// where these differ from the camel case type name.
+/// Enums with `from_*`, `as_*`, `name()`, and `Display` are generated
+/// for all types in `TYPES_CSV` with an integer base type.
+/// Labels are generated for all types in `TYPES_CSV` that fields refer to.
+const ENUM_NAMES: &[(&str, &str)] = &[
+    ("file", "FileType"),
+];
+
+/// Names that would shadow the prelude or `fmt` in the generated module,
+/// suffixed with `Type` if a type name converts to one of these.
+const RESERVED: &[&str] = &["Self", "Option", "Some", "None", "Result", "Ok", "Err", "String", "Vec", "Box"];
+
+/// Type from "Types" sheet.
+struct ProfileType {
+    name: String,
+    base_type: String,
+    /// `(value name, value)`, in the order listed.
+    values: Vec<(String, u32)>,
+}
+
+impl ProfileType {
+    fn value(&self, name: &str) -> Option<u32> {
+        self.values.iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| *v)
+    }
+}
+
+/// Field or subfield row from "Messages" sheet.
+struct ProfileField {
+    field_def_no: Option<u8>,
+    name: String,
+    field_type: String,
+    components: Vec<String>,
+    scale: Vec<String>,
+    offset: Vec<String>,
+    units: Vec<String>,
+    bits: Vec<String>,
+    accumulate: Vec<String>,
+    ref_field_name: Vec<String>,
+    ref_field_value: Vec<String>,
+    subfields: Vec<ProfileField>,
+}
+
+/// Message from "Messages" sheet.
+struct ProfileMessage {
+    global_id: u16,
+    name: String,
+    fields: Vec<ProfileField>,
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed={TYPES_CSV}");
+    println!("cargo:rerun-if-changed={MESSAGES_CSV}");
+
+    let types = read_types(TYPES_CSV);
+    let messages = read_messages(MESSAGES_CSV, &types);
+
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR not set");
+    fs::write(Path::new(&out_dir).join("types.rs"), write_types(&types, &messages))
+        .expect("Failed to write types.rs");
+    fs::write(Path::new(&out_dir).join("messages.rs"), write_messages(&messages, &types))
+        .expect("Failed to write messages.rs");
+}
+
+/// Splits CSV into rows of cells. Quoted cells may contain commas,
+/// escaped quotes (`""`), and line breaks, e.g. comments in Profile.xlsx.
+fn split_csv(csv: &str) -> Vec<Vec<String>> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut cell = String::new();
+    let mut quoted = false;
+    let mut chars = csv.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (c, quoted) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                cell.push('"');
+                chars.next();
+            },
+            ('"', _) => quoted = !quoted,
+            (',', false) => row.push(std::mem::take(&mut cell)),
+            ('\r', false) => (),
+            ('\n', false) => {
+                row.push(std::mem::take(&mut cell));
+                rows.push(std::mem::take(&mut row));
+            },
+            _ => cell.push(c),
+        }
+    }
+    if !cell.is_empty() || !row.is_empty() {
+        row.push(cell);
+        rows.push(row);
+    }
+    rows
+}
+
+/// Reads CSV rows, excluding the header row.
+/// Cells are trimmed and rows are padded to `columns` cells.
+fn read_csv(path: &str, columns: usize) -> Vec<Vec<String>> {
+    let csv = fs::read_to_string(path)
+        .unwrap_or_else(|err| panic!("Failed to read {path}: {err}"));
+    split_csv(csv.trim_start_matches('\u{feff}')).into_iter()
+        .skip(1)
+        .map(|row| {
+            let mut row: Vec<String> = row.iter()
+                .map(|cell| cell.trim().to_owned())
+                .collect();
+            row.resize(columns, String::new());
+            row
+        })
+        .collect()
+}
+
+/// Splits a comma separated cell, e.g. `"100,16"` for components.
+fn split_cell(cell: &str) -> Vec<String> {
+    match cell.is_empty() {
+        true => Vec::new(),
+        false => cell.split(',').map(|s| s.trim().to_owned()).collect()
+    }
+}
+
+/// Parses decimal or hexadecimal (`0x` prefix) values.
+fn parse_value(value: &str) -> Option<u32> {
+    match value.strip_prefix("0x").or_else(|| value.strip_prefix("0X")) {
+        Some(hex) => u32::from_str_radix(hex, 16).ok(),
+        None => value.parse().ok()
+    }
+}
+
+/// Columns: Type Name, Base Type, Value Name, Value, Comment
+///
+/// Types listed more than once only keep the values listed first.
+fn read_types(path: &str) -> Vec<ProfileType> {
+    let mut types: Vec<ProfileType> = Vec::new();
+    let mut current = false;
+    for row in read_csv(path, 5) {
+        if !row[0].is_empty() {
+            current = !types.iter().any(|t| t.name == row[0]);
+            match current {
+                true => types.push(ProfileType {
+                    name: row[0].to_owned(),
+                    base_type: row[1].to_owned(),
+                    values: Vec::new(),
+                }),
+                false => println!("cargo:warning=Duplicate type '{}' in {path}", row[0]),
+            }
+        } else if current && !row[2].is_empty() {
+            let value = match parse_value(&row[3]) {
+                Some(v) => v,
+                None => {
+                    println!("cargo:warning=Invalid value '{}' for '{}' in {path}", row[3], row[2]);
+                    continue
+                }
+            };
+            if let Some(t) = types.last_mut() {
+                t.values.push((row[2].to_owned(), value));
+            }
+        }
+    }
+    types
+}
+
+/// Columns: Message Name, Field Def #, Field Name, Field Type, Array, Components,
+/// Scale, Offset, Units, Bits, Accumulate, Ref Field Name, Ref Field Value, Comment
+///
+/// Rows with a message name not listed in `mesg_num` in the "Types" sheet,
+/// e.g. section headings, end the current message.
+/// Messages listed more than once only keep the fields listed first.
+fn read_messages(path: &str, types: &[ProfileType]) -> Vec<ProfileMessage> {
+    let mesg_num = match types.iter().find(|t| t.name == "mesg_num") {
+        Some(t) => t,
+        None => {
+            println!("cargo:warning=No 'mesg_num' type in {TYPES_CSV}");
+            return Vec::new()
+        }
+    };
+
+    let mut messages: Vec<ProfileMessage> = Vec::new();
+    let mut current = false;
+    for row in read_csv(path, 14) {
+        if !row[0].is_empty() {
+            current = match mesg_num.value(&row[0]).and_then(|v| u16::try_from(v).ok()) {
+                Some(global_id) if messages.iter().any(|m| m.global_id == global_id) => {
+                    println!("cargo:warning=Duplicate message '{}' in {path}", row[0]);
+                    false
+                },
+                Some(global_id) => {
+                    messages.push(ProfileMessage {
+                        global_id,
+                        name: row[0].to_owned(),
+                        fields: Vec::new(),
+                    });
+                    true
+                },
+                None => false
+            };
+            continue
+        }
+        if !current || row[2].is_empty() {
+            continue
+        }
+        let field = ProfileField {
+            field_def_no: row[1].parse().ok(),
+            name: row[2].to_owned(),
+            field_type: row[3].to_owned(),
+            components: split_cell(&row[5]),
+            scale: split_cell(&row[6]),
+            offset: split_cell(&row[7]),
+            units: split_cell(&row[8]),
+            bits: split_cell(&row[9]),
+            accumulate: split_cell(&row[10]),
+            ref_field_name: split_cell(&row[11]),
+            ref_field_value: split_cell(&row[12]),
+            subfields: Vec::new(),
+        };
+        let message = messages.last_mut().expect("No message");
+        match (field.field_def_no, message.fields.last_mut()) {
+            (Some(_), _) => message.fields.push(field),
+            // Rows without field definition number are subfields
+            // of the preceding field
+            (None, Some(parent)) => parent.subfields.push(field),
+            (None, None) => (),
+        }
+    }
+    messages
+}
+
+/// Converts a type or value name to a Rust identifier,
+/// e.g. `sub_sport` to `SubSport`, `4iiiis` to `FourIiiis`.
+/// Characters other than ASCII letters and digits separate words.
+fn camel_case(name: &str) -> String {
+    const DIGITS: [&str; 10] = ["Zero", "One", "Two", "Three", "Four", "Five", "Six", "Seven", "Eight", "Nine"];
+    name.split(|c: char| !c.is_ascii_alphanumeric())
+        .map(|part| {
+            let rest = part.trim_start_matches(|c: char| c.is_ascii_digit());
+            let digits: String = part[.. part.len() - rest.len()].chars()
+                .map(|c| DIGITS[c as usize - '0' as usize])
+                .collect();
+            let mut chars = rest.chars();
+            match chars.next() {
+                Some(first) => format!("{digits}{}{}", first.to_uppercase(), chars.as_str()),
+                None => digits,
+            }
+        })
+        .collect()
+}
+
+/// FIT base type number for a base type name in Profile.xlsx,
+/// e.g. `6` for `uint32`.
+fn base_type_number(base_type: &str) -> Option<u8> {
+    match base_type {
+        "enum" | "bool" => Some(0),
+        "sint8" => Some(1),
+        "uint8" => Some(2),
+        "sint16" => Some(3),
+        "uint16" => Some(4),
+        "sint32" => Some(5),
+        "uint32" => Some(6),
+        "string" => Some(7),
+        "float32" => Some(8),
+        "float64" => Some(9),
+        "uint8z" => Some(10),
+        "uint16z" => Some(11),
+        "uint32z" => Some(12),
+        "byte" => Some(13),
+        "sint64" => Some(14),
+        "uint64" => Some(15),
+        "uint64z" => Some(16),
+        _ => None
+    }
+}
+
+/// FIT base type number for the "Field Type" of `field`,
+/// via the "Types" sheet for named types such as `sport`.
+/// `None` if the field type is not specified, or is neither
+/// a base type nor listed in `TYPES_CSV`.
+fn write_base_type(field: &ProfileField, message: &ProfileMessage, types: &[ProfileType]) -> String {
+    if field.field_type.is_empty() {
+        return "None".to_owned()
+    }
+    let base_type = types.iter()
+        .find(|t| t.name == field.field_type)
+        .map_or(field.field_type.as_str(), |t| t.base_type.as_str());
+    match base_type_number(base_type) {
+        Some(n) => format!("Some({n})"),
+        None => {
+            println!("cargo:warning=Unknown field type '{}' for '{}' in '{}' in {MESSAGES_CSV}", field.field_type, field.name, message.name);
+            "None".to_owned()
+        }
+    }
+}
+
+/// Scale or offset cell as an `f64` option literal, e.g. `Some(1000.0)`,
+/// or `None` if `value` is empty or not a finite number.
+fn numerical(
+    value: Option<&String>,
+    column: &str,
+    field: &ProfileField,
+    message: &ProfileMessage
+) -> String {
+    match value.filter(|v| !v.is_empty()) {
+        Some(v) => match v.parse::<f64>() {
+            Ok(n) if n.is_finite() => format!("Some({n:?})"),
+            _ => {
+                println!("cargo:warning=Unsupported {column} '{v}' for '{}' in '{}' in {MESSAGES_CSV}", field.name, message.name);
+                "None".to_owned()
+            }
+        },
+        None => "None".to_owned()
+    }
+}
+
+/// Rust type and its largest value for FIT base type,
+/// or `None` for non-integer base types, e.g. `string`.
+fn rust_type(base_type: &str) -> Option<(&'static str, u64)> {
+    match base_type {
+        "enum" | "uint8" | "uint8z" | "byte" => Some(("u8", u8::MAX as u64)),
+        "sint8" => Some(("i8", i8::MAX as u64)),
+        "uint16" | "uint16z" => Some(("u16", u16::MAX as u64)),
+        "sint16" => Some(("i16", i16::MAX as u64)),
+        "uint32" | "uint32z" => Some(("u32", u32::MAX as u64)),
+        "sint32" => Some(("i32", i32::MAX as u64)),
+        "uint64" | "uint64z" => Some(("u64", u64::MAX)),
+        "sint64" => Some(("i64", i64::MAX as u64)),
+        _ => None
+    }
+}
+
+/// Enum name for type `type_name`, see `ENUM_NAMES`.
+fn enum_name(type_name: &str) -> String {
+    let name = match ENUM_NAMES.iter().find(|(t, _)| *t == type_name) {
+        Some((_, name)) => name.to_string(),
+        None => camel_case(type_name)
+    };
+    match RESERVED.contains(&name.as_str()) || name.is_empty() {
+        true => format!("{name}Type"),
+        false => name
+    }
+}
+
+/// Enum variants for `t` as `(variant, value name, value)`.
+/// Values that do not fit `max` are left out, and variant names
+/// that are already taken are suffixed with the value.
+fn variants(t: &ProfileType, max: u64) -> Vec<(String, &str, u32)> {
+    let mut variants: Vec<(String, &str, u32)> = Vec::new();
+    for (name, value) in t.values.iter() {
+        if *value as u64 > max {
+            println!("cargo:warning=Value {value} for '{name}' does not fit '{}' in {TYPES_CSV}", t.name);
+            continue
+        }
+        let mut variant = camel_case(name);
+        if variant.is_empty() {
+            variant = "Value".to_owned();
+        }
+        if variant == "Self" || variants.iter().any(|(v, _, _)| *v == variant) {
+            variant = format!("{variant}{value}");
+        }
+        if variants.iter().any(|(v, _, _)| *v == variant) {
+            println!("cargo:warning=Duplicate value name '{name}' for '{}' in {TYPES_CSV}", t.name);
+            continue
+        }
+        variants.push((variant, name.as_str(), *value));
+    }
+    variants
+}
+
+fn write_types(types: &[ProfileType], messages: &[ProfileMessage]) -> String {
+    let mut out = String::new();
+
+    let mut enum_names: Vec<String> = Vec::new();
+    for t in types.iter() {
+        let (ty, max) = match rust_type(&t.base_type) {
+            Some(r) => r,
+            None => continue
+        };
+        let variants = variants(t, max);
+        if variants.is_empty() {
+            continue
+        }
+        let enum_name = enum_name(&t.name);
+        if enum_names.contains(&enum_name) {
+            println!("cargo:warning=Duplicate enum name '{enum_name}' for '{}' in {TYPES_CSV}", t.name);
+            continue
+        }
+        enum_names.push(enum_name.to_owned());
+        let type_name = &t.name;
+
+        writeln!(out, "/// Profile.xlsx \"Types\" sheet `{type_name}`.").unwrap();
+        writeln!(out, "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]").unwrap();
+        writeln!(out, "#[cfg_attr(feature = \"serde\", derive(serde::Serialize, serde::Deserialize))]").unwrap();
+        writeln!(out, "pub enum {enum_name} {{").unwrap();
+        for (variant, _, _) in variants.iter() {
+            writeln!(out, "    {variant},").unwrap();
+        }
+        writeln!(out, "}}\n").unwrap();
+
+        writeln!(out, "impl {enum_name} {{").unwrap();
+        writeln!(out, "    /// Returns `{enum_name}` from its raw value,").unwrap();
+        writeln!(out, "    /// or `None` if the value is not covered.").unwrap();
+        writeln!(out, "    pub fn from_{ty}(value: {ty}) -> Option<Self> {{").unwrap();
+        writeln!(out, "        match value {{").unwrap();
+        // Names sharing a value with an earlier name are only
+        // returned by `from_*` for the earlier name
+        let mut seen: Vec<u32> = Vec::new();
+        for (variant, _, value) in variants.iter() {
+            if !seen.contains(value) {
+                writeln!(out, "            {value} => Some(Self::{variant}),").unwrap();
+                seen.push(*value);
+            }
+        }
+        writeln!(out, "            _ => None\n        }}\n    }}\n").unwrap();
+        writeln!(out, "    /// Returns the raw value as `{ty}`.").unwrap();
+        writeln!(out, "    pub fn as_{ty}(&self) -> {ty} {{").unwrap();
+        writeln!(out, "        match self {{").unwrap();
+        for (variant, _, value) in variants.iter() {
+            writeln!(out, "            Self::{variant} => {value},").unwrap();
+        }
+        writeln!(out, "        }}\n    }}\n").unwrap();
+        writeln!(out, "    /// Returns the name as listed in Profile.xlsx.").unwrap();
+        writeln!(out, "    pub fn name(&self) -> &'static str {{").unwrap();
+        writeln!(out, "        match self {{").unwrap();
+        for (variant, name, _) in variants.iter() {
+            writeln!(out, "            Self::{variant} => {name:?},").unwrap();
+        }
+        writeln!(out, "        }}\n    }}\n}}\n").unwrap();
+
+        writeln!(out, "impl fmt::Display for {enum_name} {{").unwrap();
+        writeln!(out, "    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {{").unwrap();
+        writeln!(out, "        write!(f, \"{{}}\", self.name())").unwrap();
+        writeln!(out, "    }}\n}}\n").unwrap();
+    }
+
+    // Value names by value, for types that fields or subfields refer to
+    let referenced = |name: &str| messages.iter()
+        .flat_map(|m| m.fields.iter())
+        .any(|f| f.field_type == name || f.subfields.iter().any(|s| s.field_type == name));
+    for t in types.iter().filter(|t| referenced(&t.name)) {
+        let mut values = phf_codegen::Map::<u32>::new();
+        let mut seen: Vec<u32> = Vec::new();
+        for (name, value) in t.values.iter() {
+            // Names sharing a value with an earlier name are skipped
+            if !seen.contains(value) {
+                values.entry(*value, &format!("{name:?}"));
+                seen.push(*value);
+            }
+        }
+        writeln!(
+            out,
+            "/// Value names for `{}` by value.\n\
+            pub(crate) static {}: phf::Map<u32, &'static str> = {};\n",
+            t.name,
+            labels_static(&t.name),
+            values.build()
+        ).unwrap();
+    }
+
+    out
+}
+
+/// Name of the static with value names for a type.
+fn labels_static(type_name: &str) -> String {
+    format!("LABELS_{}", type_name.to_uppercase())
+}
+
+/// Value names for `field_type` as an option literal,
+/// or `None` if the type is not listed in `TYPES_CSV`,
+/// e.g. base types.
+fn write_labels(field_type: &str, types: &[ProfileType]) -> String {
+    match types.iter().any(|t| t.name == field_type) {
+        true => format!("Some(&crate::profile::types::{})", labels_static(field_type)),
+        false => "None".to_owned()
+    }
+}
+
+/// String option literal, e.g. `Some("m")`, or `None` if `value` is empty.
+fn option(value: Option<&String>) -> String {
+    match value.filter(|v| !v.is_empty()) {
+        Some(v) => format!("Some({v:?})"),
+        None => "None".to_owned(),
+    }
+}
+
+/// Components as an array literal of `FitComponent`.
+/// Component names are resolved to field definition numbers
+/// in the same message.
+fn write_components(field: &ProfileField, message: &ProfileMessage) -> String {
+    let components: Vec<String> = field.components.iter()
+        .enumerate()
+        .filter_map(|(i, name)| {
+            let destination = message.fields.iter().find(|f| &f.name == name);
+            let field_def_no = match destination.and_then(|f| f.field_def_no) {
+                Some(n) => n,
+                None => {
+                    println!("cargo:warning=Unknown component '{name}' for '{}' in '{}'", field.name, message.name);
+                    return None
+                }
+            };
+            let bits = match field.bits.get(i).and_then(|b| b.parse::<u8>().ok()) {
+                Some(b) if b <= 64 => b,
+                _ => {
+                    println!("cargo:warning=Invalid bits for component '{name}' of '{}' in '{}' in {MESSAGES_CSV}", field.name, message.name);
+                    return None
+                }
+            };
+            Some(format!(
+                "FitComponent {{ field_def_no: {field_def_no}, bits: {bits}, scale: {}, offset: {}, accumulate: {} }}",
+                numerical(field.scale.get(i), "scale", field, message),
+                numerical(field.offset.get(i), "offset", field, message),
+                field.accumulate.get(i).is_some_and(|a| a == "1"),
+            ))
+        })
+        .collect();
+    format!("&[{}]", components.join(", "))
+}
+
+/// Scale, offset, and units for a field or subfield.
+/// These are listed per component for fields
+/// with more than one component, and do not apply to the field itself.
+fn write_attributes(field: &ProfileField, message: &ProfileMessage) -> String {
+    let single = field.components.len() <= 1;
+    let get = |values: &[String]| match single {
+        true => values.first().cloned(),
+        false => None
+    };
+    format!(
+        "scale: {}, offset: {}, units: {}",
+        numerical(get(&field.scale).as_ref(), "scale", field, message),
+        numerical(get(&field.offset).as_ref(), "offset", field, message),
+        option(get(&field.units).as_ref()),
+    )
+}
+
+/// Subfield reference fields as an array literal of `(field_def_no, value)`.
+/// Reference values are resolved via the reference field's type.
+fn write_references(subfield: &ProfileField, message: &ProfileMessage, types: &[ProfileType]) -> String {
+    let references: Vec<String> = subfield.ref_field_name.iter()
+        .zip(subfield.ref_field_value.iter())
+        .filter_map(|(name, value)| {
+            let reference = message.fields.iter().find(|f| &f.name == name);
+            let resolved = reference.and_then(|r| {
+                let value = types.iter()
+                    .find(|t| t.name == r.field_type)
+                    .and_then(|t| t.value(value))
+                    .or_else(|| parse_value(value));
+                r.field_def_no.zip(value)
+            });
+            match resolved {
+                Some((field_def_no, value)) => Some(format!("({field_def_no}, {value})")),
+                None => {
+                    println!("cargo:warning=Unresolved reference '{name}' = '{value}' for '{}' in '{}'", subfield.name, message.name);
+                    None
+                }
+            }
+        })
+        .collect();
+    format!("&[{}]", references.join(", "))
+}
+
+fn write_messages(messages: &[ProfileMessage], types: &[ProfileType]) -> String {
+    let mut map = phf_codegen::Map::<u16>::new();
+    for message in messages.iter() {
+        let mut fields: Vec<(u8, String)> = Vec::new();
+        for field in message.fields.iter() {
+            let field_def_no = match field.field_def_no {
+                Some(n) => n,
+                None => continue
+            };
+            let subfields: Vec<String> = field.subfields.iter()
+                .map(|sub| format!(
+                    "FitSubfield {{ name: {:?}, field_type: {:?}, labels: {}, {}, references: {}, components: {} }}",
+                    sub.name,
+                    sub.field_type,
+                    write_labels(&sub.field_type, types),
+                    write_attributes(sub, message),
+                    write_references(sub, message, types),
+                    write_components(sub, message),
+                ))
+                .collect();
+            fields.push((field_def_no, format!(
+                "FitFieldType {{ field_def_no: {field_def_no}, name: {:?}, field_type: {:?}, base_type: {}, labels: {}, {}, components: {}, subfields: &[{}] }}",
+                field.name,
+                field.field_type,
+                write_base_type(field, message, types),
+                write_labels(&field.field_type, types),
+                write_attributes(field, message),
+                write_components(field, message),
+                subfields.join(", "),
+            )));
+        }
+        // Sorted for `FitMessageType::field()`
+        fields.sort_by_key(|(field_def_no, _)| *field_def_no);
+        fields.dedup_by_key(|(field_def_no, _)| *field_def_no);
+        map.entry(message.global_id, &format!(
+            "FitMessageType {{ global_id: {}, name: {:?}, fields: &[{}] }}",
+            message.global_id,
+            message.name,
+            fields.into_iter().map(|(_, f)| f).collect::<Vec<_>>().join(",\n"),
+        ));
+    }
+    format!(
+        "/// Message types by FIT global ID.\n\
+        pub(crate) static MESSAGES: phf::Map<u16, FitMessageType> = {};\n",
+        map.build()
+    )
+}
@@ -1,4 +1,17 @@
 use time::{macros::datetime, PrimitiveDateTime};
 
 /// FIT base start time 1989-12-31T00:00:00.000.
-pub const FIT_DEFAULT_DATETIME: PrimitiveDateTime = datetime!(1989-12-31 00:00:00.000);
\ No newline at end of file
+pub const FIT_DEFAULT_DATETIME: PrimitiveDateTime = datetime!(1989-12-31 00:00:00.000);
+
+/// FIT `date_time` values below this are relative system time,
+/// i.e. seconds since device power-on, rather than seconds since
+/// `FIT_DEFAULT_DATETIME` (UTC). See FIT SDK, "date_time" in Profile.xlsx.
+pub const FIT_MIN_ABSOLUTE_TIMESTAMP: u32 = 0x10000000;
+
+/// FIT protocol version for written FIT files, 2.0,
+/// see `FitWriter`. Upper nibble is the major version.
+pub const FIT_PROTOCOL_VERSION: u8 = 0x20;
+
+/// FIT profile version for written FIT files, 21.95,
+/// see `FitWriter`. Major version times 100, plus minor version.
+pub const FIT_PROFILE_VERSION: u16 = 2195;
@@ -31,9 +31,19 @@ pub enum FitError {
     /// Unexpected FIT header size.
     /// Valid sizes are 12 or 14 bytes.
     UnexpectedHeaderSize(usize),
+    /// FIT header CRC does not match computed value.
+    HeaderCrcMismatch{expected: u16, got: u16},
+    /// FIT file CRC does not match computed value.
+    FileCrcMismatch{expected: u16, got: u16},
     /// Unknown local id (u4).
     /// Matches definitions with data messages.
     UnknownDefinition{local: u8, offset: u64},
+    /// Compressed timestamp header without a preceding
+    /// full timestamp (field `253`) to resolve it against.
+    NoReferenceTimestamp{local: u8, offset: u64},
+    /// Data ended mid-message at offset,
+    /// e.g. a FIT-file that was not closed properly.
+    Truncated{offset: u64},
     /// Unknown FIT base type number.
     UnknownBaseType(u8),
     /// Unknown developer field description (FIT global ID 206)
@@ -44,8 +54,17 @@ pub enum FitError {
     ErrorParsingField{global: u16, field_def_no: u8},
     /// Error assigning data message field.
     ErrorAssigningField{global: u16, field_def_no: u8},
+    /// Data message field only contains invalid values,
+    /// i.e. the FIT SDK sentinel for missing data.
+    InvalidFieldValue{global: u16, field_def_no: u8},
     /// Error parsing data message field.
     ErrorParsingMessage(u16),
+    /// Field value too large to encode,
+    /// since field size is at most 255 bytes.
+    InvalidFieldSize{global: u16, field_def_no: u8, size: usize},
+    /// Developer field without a developer data index,
+    /// so no field description can be written.
+    MissingDeveloperDataIndex{field_number: u8},
     /// Invalid VIRB MP4 file, i.e. it no UUID embeded.
     InvalidVirbMp4,
     /// Invalid VIRB MP4 file, i.e. it no UUID embeded.
@@ -54,6 +73,19 @@ pub enum FitError {
     NoData,
 }
 
+impl FitError {
+    /// Returns `true` if the error was caused by
+    /// reaching the end of data, e.g. for truncated FIT-files.
+    pub fn is_eof(&self) -> bool {
+        match self {
+            FitError::BinReadError(err) => err.is_eof(),
+            FitError::IOError(err) => err.kind() == std::io::ErrorKind::UnexpectedEof,
+            FitError::Truncated{..} => true,
+            _ => false
+        }
+    }
+}
+
 impl std::error::Error for FitError {}
 impl fmt::Display for FitError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -70,8 +102,16 @@ impl fmt::Display for FitError {
             FitError::InvalidArchitecture{arch, pos} => write!(f, "Invalid architecture: {arch} at position {pos}. Must be 0 (Little Endian), or 1 (Big Endian)."),
             FitError::UnexpectedHeaderSize(size) => write!(f,
                 "Unexpected header size {size}. Valid sizes are 12 or 14 bytes."),
+            FitError::HeaderCrcMismatch{expected, got} => write!(f,
+                "Header CRC mismatch: Expected 0x{expected:04X}, got 0x{got:04X}."),
+            FitError::FileCrcMismatch{expected, got} => write!(f,
+                "File CRC mismatch: Expected 0x{expected:04X}, got 0x{got:04X}."),
             FitError::UnknownDefinition{local, offset} => write!(f,
                 "Unknown local definition ID {local} at offset {offset}."),
+            FitError::NoReferenceTimestamp{local, offset} => write!(f,
+                "Compressed timestamp header for local ID {local} at offset {offset} has no preceding full timestamp."),
+            FitError::Truncated{offset} => write!(f,
+                "Unexpected end of data for message at offset {offset}."),
             FitError::UnknownBaseType(id) => write!(f,
                 "Unknown base type ID {id}."),
             FitError::UnknownFieldDescription{field_number, developer_data_index} => write!(f,
@@ -82,8 +122,14 @@ impl fmt::Display for FitError {
                 "Failed to parse field with FIT global ID {global}, field definition number {field_def_no}."),
             FitError::ErrorAssigningField{global, field_def_no} => write!(f,
                 "Failed to assign field with FIT global ID {global}, field definition number {field_def_no}."),
+            FitError::InvalidFieldValue{global, field_def_no} => write!(f,
+                "Invalid value for field with FIT global ID {global}, field definition number {field_def_no}."),
             FitError::ErrorParsingMessage(global) => write!(f,
                 "Failed to parse message with FIT global ID {global}."),
+            FitError::InvalidFieldSize{global, field_def_no, size} => write!(f,
+                "Field with FIT global ID {global}, field definition number {field_def_no} is {size} bytes. Maximum is 255 bytes."),
+            FitError::MissingDeveloperDataIndex{field_number} => write!(f,
+                "Developer field with field number {field_number} has no developer data index."),
             FitError::InvalidVirbMp4 => write!(f,
                 "Not a valid VIRB MP4-file."),
             FitError::PathNotSet => write!(f,
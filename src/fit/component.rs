@@ -0,0 +1,325 @@
+//! Component field expansion.
+//!
+//! Some fields in Profile.xlsx consist of bit-packed components
+//! that expand into other fields in the same message, e.g.
+//! `compressed_speed_distance` into `speed` and `distance`,
+//! or `speed` into `enhanced_speed` for `record/20`.
+//! Derived fields expand in turn, so `compressed_speed_distance`
+//! also results in `enhanced_speed`.
+
+use std::collections::HashMap;
+
+use crate::profile::{FitComponent, FitFieldType, FitMessageType};
+
+use super::{DataField, DataMessage, Value};
+
+/// Expands component fields into derived fields.
+/// Accumulated components are tracked across data messages,
+/// so these must be expanded in logging order.
+#[derive(Debug, Default)]
+pub(crate) struct ComponentExpander {
+    /// Accumulated values by FIT global ID and destination field definition number.
+    accumulators: HashMap<(u16, u8), Accumulator>,
+}
+
+/// Rolling value accumulated over consecutive data messages.
+#[derive(Debug, Default)]
+struct Accumulator {
+    value: u64,
+    last: u64,
+}
+
+impl Accumulator {
+    /// Adds the difference to the last value,
+    /// accounting for rollover at `bits`.
+    fn accumulate(&mut self, value: u64, bits: u8) -> u64 {
+        self.value += value.wrapping_sub(self.last) & mask(bits);
+        self.last = value;
+        self.value
+    }
+}
+
+impl ComponentExpander {
+    /// Resets accumulated values, e.g. at the start of a chained FIT file.
+    pub(crate) fn reset(&mut self) {
+        self.accumulators.clear();
+    }
+
+    /// Adds derived fields for component fields in `data`.
+    /// Fields already logged in `data` are not overwritten,
+    /// unless these only contain invalid values.
+    pub(crate) fn expand(&mut self, data: &mut DataMessage) {
+        let global = data.global;
+        let message_type = match FitMessageType::get(global) {
+            Some(mt) => mt,
+            None => return
+        };
+
+        // Fields derived in this pass, which several components may add values to
+        let mut derived: Vec<u8> = Vec::new();
+
+        // Derived fields are pushed to `data.fields`
+        // and will be expanded in turn
+        let mut i = 0;
+        while i < data.fields.len() {
+            let field = &data.fields[i];
+            i += 1;
+
+            // Components for the subfield that applies, if any
+            let components = match message_type.field(field.field_def_no()) {
+                Some(field_type) => field_type.components(&data.fields),
+                None => continue
+            };
+            if components.is_empty() {
+                continue
+            }
+            if field.data.is_invalid() {
+                continue
+            }
+            let bytes = match field.data.to_le_bytes() {
+                Some(b) => b,
+                None => continue
+            };
+
+            let mut position = 0;
+            for component in components.iter() {
+                let bits = match read_bits(&bytes, position, component.bits) {
+                    Some(b) => b,
+                    None => break
+                };
+                position += component.bits as usize;
+
+                // All bits set means the component is invalid
+                if bits == mask(component.bits) {
+                    continue
+                }
+
+                // Keep logged fields, unless these only contain invalid values
+                let existing = data.fields.iter()
+                    .position(|f| f.field_def_no() == component.field_def_no);
+                if let Some(index) = existing {
+                    if !derived.contains(&component.field_def_no) {
+                        if !data.fields[index].data.is_invalid() {
+                            continue
+                        }
+                        data.fields.remove(index);
+                        if index < i {
+                            i -= 1;
+                        }
+                    }
+                }
+
+                let bits = match component.accumulate {
+                    true => self.accumulators.entry((global, component.field_def_no))
+                        .or_default()
+                        .accumulate(bits, component.bits),
+                    false => bits
+                };
+
+                let destination = message_type.field(component.field_def_no);
+                let value = convert(bits, component, destination);
+
+                match data.fields.iter_mut().find(|f| f.field_def_no() == component.field_def_no) {
+                    Some(field) => push(field, value),
+                    None => {
+                        // All bits set is invalid, so one less is the largest value
+                        let max = convert(mask(component.bits) - 1, component, destination);
+                        data.fields.push(DataField::component(component.field_def_no, new_value(value, max, component, destination)));
+                        derived.push(component.field_def_no);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Bit mask for `bits` number of bits.
+fn mask(bits: u8) -> u64 {
+    match bits {
+        64.. => u64::MAX,
+        b => (1 << b) - 1
+    }
+}
+
+/// Reads `bits` number of bits starting at bit `position`,
+/// least significant bit first.
+/// Returns `None` if `bytes` contains too few bits.
+fn read_bits(bytes: &[u8], position: usize, bits: u8) -> Option<u64> {
+    if bits == 0 || bits > 64 || position + bits as usize > bytes.len() * 8 {
+        return None
+    }
+    Some((0 .. bits as usize).fold(0, |value, i| {
+        let bit = position + i;
+        let set = (bytes[bit / 8] >> (bit % 8)) & 1;
+        value | ((set as u64) << i)
+    }))
+}
+
+/// Converts component bits to the scale and offset of the destination field.
+fn convert(bits: u64, component: &FitComponent, destination: Option<&FitFieldType>) -> u64 {
+    let (scale, offset) = match destination {
+        Some(d) => (d.scale, d.offset),
+        None => (None, None)
+    };
+    if scale == component.scale && offset == component.offset {
+        return bits
+    }
+    let value = bits as f64 / component.scale.unwrap_or(1.)
+        - component.offset.unwrap_or(0.);
+    let raw = (value + offset.unwrap_or(0.)) * scale.unwrap_or(1.);
+    raw.round().max(0.) as u64
+}
+
+/// New value for a derived field, with the base type of the
+/// destination field, if listed in the "Field Type" column in Profile.xlsx.
+///
+/// Otherwise, or for floats, the base type is the smallest
+/// unsigned integer that fits `max`, the largest possible value
+/// for the component after conversion,
+/// and accumulated values are always `Value::Uint32`.
+fn new_value(value: u64, max: u64, component: &FitComponent, destination: Option<&FitFieldType>) -> Value {
+    match destination.and_then(|d| d.base_type) {
+        Some(0) => Value::Enum(vec![value as u8]),
+        Some(1) => Value::Sint8(vec![value as i8]),
+        Some(2) => Value::Uint8(vec![value as u8]),
+        Some(3) => Value::Sint16(vec![value as i16]),
+        Some(4) => Value::Uint16(vec![value as u16]),
+        Some(5) => Value::Sint32(vec![value as i32]),
+        Some(6) => Value::Uint32(vec![value as u32]),
+        Some(10) => Value::Uint8z(vec![value as u8]),
+        Some(11) => Value::Uint16z(vec![value as u16]),
+        Some(12) => Value::Uint32z(vec![value as u32]),
+        Some(13) => Value::Byte(vec![value as u8]),
+        Some(14) => Value::Sint64(vec![value as i64]),
+        Some(15) => Value::Uint64(vec![value]),
+        Some(16) => Value::Uint64z(vec![value]),
+        _ => match component.accumulate {
+            false if max < u8::MAX as u64 => Value::Uint8(vec![value as u8]),
+            false if max < u16::MAX as u64 => Value::Uint16(vec![value as u16]),
+            _ => Value::Uint32(vec![value as u32]),
+        }
+    }
+}
+
+/// Adds a value to a derived field
+/// that several components expand into.
+fn push(field: &mut DataField, value: u64) {
+    match &mut field.data {
+        Value::Enum(v) | Value::Uint8(v) | Value::Uint8z(v) | Value::Byte(v) => v.push(value as u8),
+        Value::Sint8(v) => v.push(value as i8),
+        Value::Sint16(v) => v.push(value as i16),
+        Value::Uint16(v) | Value::Uint16z(v) => v.push(value as u16),
+        Value::Sint32(v) => v.push(value as i32),
+        Value::Uint32(v) | Value::Uint32z(v) => v.push(value as u32),
+        Value::Sint64(v) => v.push(value as i64),
+        Value::Uint64(v) | Value::Uint64z(v) => v.push(value),
+        Value::String(_) | Value::Float32(_) | Value::Float64(_) => return
+    }
+    field.definition.size += field.definition.base_type.base_len().unwrap_or(1);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `record/20` with `compressed_speed_distance`, speed 5 m/s
+    /// (500 at scale 100) and distance `distance` (at scale 16).
+    fn compressed_speed_distance(distance: u64) -> DataMessage {
+        let packed = 500 | (distance << 12);
+        DataMessage {
+            global: 20,
+            fields: vec![DataField::from_value(8, Value::Byte(packed.to_le_bytes()[.. 3].to_vec()))],
+            ..Default::default()
+        }
+    }
+
+    /// Raw value for field `field_def_no`.
+    fn raw(data: &DataMessage, field_def_no: u8) -> Option<f64> {
+        data.fields.iter()
+            .find(|f| f.field_def_no() == field_def_no)
+            .and_then(|f| f.data.as_f64_at(0))
+    }
+
+    #[test]
+    fn mask_bits() {
+        assert_eq!(mask(0), 0);
+        assert_eq!(mask(12), 0xFFF);
+        assert_eq!(mask(64), u64::MAX);
+    }
+
+    #[test]
+    fn read_bits_lsb_first() {
+        let bytes = [0xF4, 0x01, 0x0A];
+        assert_eq!(read_bits(&bytes, 0, 12), Some(0x1F4));
+        assert_eq!(read_bits(&bytes, 12, 12), Some(0x0A0));
+        assert_eq!(read_bits(&bytes, 4, 8), Some(0x1F));
+        // Too few bits
+        assert_eq!(read_bits(&bytes, 16, 12), None);
+        assert_eq!(read_bits(&bytes, 0, 0), None);
+    }
+
+    #[test]
+    fn convert_scale() {
+        let record = FitMessageType::get(20).unwrap();
+        let speed = record.field(6);
+
+        // 500 at scale 100 is 5 m/s, i.e. 5000 at scale 1000
+        let component = FitComponent::new(6, 12, Some(100.), None, false);
+        assert_eq!(convert(500, &component, speed), 5000);
+
+        // Same scale and offset
+        let component = FitComponent::new(6, 16, Some(1000.), None, false);
+        assert_eq!(convert(5000, &component, speed), 5000);
+
+        // Destination without scale
+        assert_eq!(convert(5000, &component, None), 5);
+    }
+
+    #[test]
+    fn accumulate_rollover() {
+        let mut accumulator = Accumulator::default();
+        assert_eq!(accumulator.accumulate(4000, 12), 4000);
+        // Rolls over at 4096
+        assert_eq!(accumulator.accumulate(10, 12), 4106);
+        assert_eq!(accumulator.accumulate(20, 12), 4116);
+    }
+
+    #[test]
+    fn expand_compressed_speed_distance() {
+        let mut expander = ComponentExpander::default();
+
+        let mut data = compressed_speed_distance(160);
+        expander.expand(&mut data);
+        // speed, 5 m/s at scale 1000
+        assert_eq!(raw(&data, 6), Some(5000.));
+        // distance, 10 m at scale 100
+        assert_eq!(raw(&data, 5), Some(1000.));
+        // speed expands in turn into enhanced_speed
+        assert_eq!(raw(&data, 73), Some(5000.));
+
+        // Distance rolls over at 4096 / 16 = 256 m
+        let mut data = compressed_speed_distance(4000);
+        expander.expand(&mut data);
+        assert_eq!(raw(&data, 5), Some(25000.));
+        let mut data = compressed_speed_distance(16);
+        expander.expand(&mut data);
+        assert_eq!(raw(&data, 5), Some(25700.));
+
+        expander.reset();
+        let mut data = compressed_speed_distance(160);
+        expander.expand(&mut data);
+        assert_eq!(raw(&data, 5), Some(1000.));
+    }
+
+    #[test]
+    fn expand_keeps_logged_fields() {
+        let mut expander = ComponentExpander::default();
+
+        let mut data = compressed_speed_distance(160);
+        data.fields.push(DataField::from_value(6, Value::Uint16(vec![4000])));
+        expander.expand(&mut data);
+        assert_eq!(raw(&data, 6), Some(4000.));
+        // enhanced_speed from the logged speed
+        assert_eq!(raw(&data, 73), Some(4000.));
+    }
+}
@@ -0,0 +1,149 @@
+//! FIT CRC-16, used for the optional header CRC and the trailing file CRC.
+//! See the [FIT SDK](https://developer.garmin.com/fit/protocol/) for the algorithm.
+
+use std::{fmt, io::{BufRead, Read}};
+
+/// FIT CRC-16 lookup table.
+const CRC_TABLE: [u16; 16] = [
+    0x0000, 0xCC01, 0xD801, 0x1400, 0xF001, 0x3C00, 0x2800, 0xE401,
+    0xA001, 0x6C00, 0x7800, 0xB401, 0x5000, 0x9C01, 0x8801, 0x4400,
+];
+
+/// Update `crc` with a single byte.
+#[inline]
+pub(crate) fn crc16_byte(crc: u16, byte: u8) -> u16 {
+    // lower nibble
+    let mut tmp = CRC_TABLE[(crc & 0xF) as usize];
+    let mut crc = (crc >> 4) & 0x0FFF;
+    crc = crc ^ tmp ^ CRC_TABLE[(byte & 0xF) as usize];
+    // upper nibble
+    tmp = CRC_TABLE[(crc & 0xF) as usize];
+    crc = (crc >> 4) & 0x0FFF;
+    crc ^ tmp ^ CRC_TABLE[((byte >> 4) & 0xF) as usize]
+}
+
+/// Compute FIT CRC-16 for `bytes`.
+pub fn crc16(bytes: &[u8]) -> u16 {
+    bytes.iter()
+        .fold(0, |crc, byte| crc16_byte(crc, *byte))
+}
+
+/// Reader that computes FIT CRC-16 over
+/// all bytes read so far, for CRC checks
+/// on non-seekable streams.
+#[derive(Debug)]
+pub(crate) struct CrcReader<R> {
+    inner: R,
+    crc: u16,
+}
+
+impl<R> CrcReader<R> {
+    pub(crate) fn new(inner: R) -> Self {
+        Self {inner, crc: 0}
+    }
+
+    /// CRC for all bytes read since last reset.
+    pub(crate) fn crc(&self) -> u16 {
+        self.crc
+    }
+
+    /// Reset CRC, e.g. at the start of a chained FIT file.
+    pub(crate) fn reset(&mut self) {
+        self.crc = 0;
+    }
+}
+
+impl<R: BufRead> CrcReader<R> {
+    /// Returns `true` if there are no more bytes to read.
+    pub(crate) fn is_eof(&mut self) -> std::io::Result<bool> {
+        self.inner.fill_buf().map(|b| b.is_empty())
+    }
+
+    /// Returns buffered bytes without consuming them.
+    /// May return fewer bytes than remain in the stream.
+    pub(crate) fn peek(&mut self) -> std::io::Result<&[u8]> {
+        self.inner.fill_buf()
+    }
+}
+
+impl<R: Read> Read for CrcReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.crc = buf[..n].iter()
+            .fold(self.crc, |crc, byte| crc16_byte(crc, *byte));
+        Ok(n)
+    }
+}
+
+/// Result of a CRC check.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CrcStatus {
+    /// Stored CRC matches the computed value.
+    Valid,
+    /// Stored CRC does not match the computed value.
+    Mismatch{expected: u16, got: u16},
+    /// No CRC stored. For the header CRC this means either
+    /// a 12 byte header, or a stored value of `0x0000`,
+    /// which signals that the CRC was not computed.
+    /// For the file CRC this means the file was truncated.
+    #[default]
+    Absent,
+}
+
+impl CrcStatus {
+    /// Compare stored CRC with the CRC computed over `bytes`.
+    /// `stored` set to `None` results in `CrcStatus::Absent`.
+    pub fn new(bytes: &[u8], stored: Option<u16>) -> Self {
+        match stored {
+            Some(_) => Self::compare(stored, crc16(bytes)),
+            None => Self::Absent
+        }
+    }
+
+    /// Compare stored CRC with an already computed CRC.
+    /// `stored` set to `None` results in `CrcStatus::Absent`.
+    pub(crate) fn compare(stored: Option<u16>, got: u16) -> Self {
+        match stored {
+            Some(expected) if expected == got => Self::Valid,
+            Some(expected) => Self::Mismatch{expected, got},
+            None => Self::Absent
+        }
+    }
+
+    /// Returns `true` if stored CRC
+    /// does not match computed value.
+    pub fn is_mismatch(&self) -> bool {
+        matches!(self, Self::Mismatch{..})
+    }
+}
+
+impl fmt::Display for CrcStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CrcStatus::Valid => write!(f, "valid"),
+            CrcStatus::Mismatch{expected, got} => write!(f, "mismatch (expected 0x{expected:04X}, got 0x{got:04X})"),
+            CrcStatus::Absent => write!(f, "absent"),
+        }
+    }
+}
+
+/// CRC status for FIT header and FIT file.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FitCrc {
+    /// Optional header CRC, bytes 12-13 in 14 byte headers.
+    /// Covers header bytes 0-11.
+    pub header: CrcStatus,
+    /// Trailing file CRC, the 2 bytes following the data section.
+    /// Covers header and data.
+    pub file: CrcStatus,
+}
+
+impl FitCrc {
+    /// Returns `true` if neither header nor file CRC
+    /// is a mismatch. Absent CRCs are accepted.
+    pub fn is_valid(&self) -> bool {
+        !self.header.is_mismatch() && !self.file.is_mismatch()
+    }
+}
@@ -1,32 +1,89 @@
 //! FIT field data attributes as described in Profile.xlsx in the [FIT SDK](https://developer.garmin.com/fit/).
 
-use std::fmt;
+use std::{borrow::Cow, fmt};
 
 use crate::types::FieldDescriptionMessage;
 
+/// Field name, scale, offset, and units.
+/// Name and units reference the static FIT profile
+/// for standard fields, and are only owned
+/// for developer fields.
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DataFieldAttributes {
-    pub name: String,
-    pub scale: Option<u32>,
-    pub offset: Option<i32>,
-    pub units: Option<String>
+    pub name: Cow<'static, str>,
+    pub scale: Option<f64>,
+    pub offset: Option<f64>,
+    pub units: Option<Cow<'static, str>>,
+    /// Name of the value for enum fields,
+    /// e.g. `cycling` for `sport`.
+    /// Not deserialized, since it references the static FIT profile.
+    /// Run `Fit::augment()` to restore.
+    #[cfg_attr(feature = "serde", serde(skip_deserializing))]
+    pub label: Option<&'static str>,
+    /// Developer data index for developer fields,
+    /// see `FieldDescriptionMessage`.
+    pub developer_data_index: Option<u8>,
+    /// Application ID for developer fields,
+    /// e.g. the Connect IQ app that logged the field,
+    /// see `DeveloperDataId`.
+    pub application_id: Option<[u8; 16]>,
+    /// Native message type for developer fields that
+    /// map to a native field, see `FieldDescriptionMessage`.
+    /// `None` means the message the field is logged in.
+    pub native_mesg_num: Option<u16>,
+    /// Native field definition number for developer fields
+    /// that map to a native field, e.g. `7` for `record.power`.
+    pub native_field_num: Option<u8>,
 }
 
 impl DataFieldAttributes {
+    /// Attributes for a standard field in the FIT profile.
+    /// Nothing is copied, since name and units are static.
+    pub fn profile(
+        name: &'static str,
+        scale: Option<f64>,
+        offset: Option<f64>,
+        units: Option<&'static str>,
+        label: Option<&'static str>
+    ) -> Self {
+        Self {
+            name: Cow::Borrowed(name),
+            scale,
+            offset,
+            units: units.map(Cow::Borrowed),
+            label,
+            developer_data_index: None,
+            application_id: None,
+            native_mesg_num: None,
+            native_field_num: None,
+        }
+    }
+
     pub fn augment(field_description: &FieldDescriptionMessage) -> Self {
         Self{
-            name: field_description.field_name.to_owned(),
-            scale: field_description.scale.map(u32::from),
-            offset: field_description.offset.map(i32::from),
-            units: field_description.units.to_owned(),
+            name: Cow::Owned(field_description.field_name.to_owned()),
+            scale: field_description.scale.map(f64::from),
+            offset: field_description.offset.map(f64::from),
+            units: field_description.units.to_owned().map(Cow::Owned),
+            label: None,
+            developer_data_index: Some(field_description.developer_data_index),
+            application_id: None,
+            native_mesg_num: field_description.native_mesg_num,
+            native_field_num: field_description.native_field_num,
         }
     }
 
     pub fn augment_mut(&mut self, field_description: &FieldDescriptionMessage) {
-        self.name = field_description.field_name.to_owned();
-        self.scale = field_description.scale.map(u32::from);
-        self.offset = field_description.offset.map(i32::from);
-        self.units = field_description.units.to_owned();
+        self.name = Cow::Owned(field_description.field_name.to_owned());
+        self.scale = field_description.scale.map(f64::from);
+        self.offset = field_description.offset.map(f64::from);
+        self.units = field_description.units.to_owned().map(Cow::Owned);
+        self.label = None;
+        self.developer_data_index = Some(field_description.developer_data_index);
+        self.application_id = None;
+        self.native_mesg_num = field_description.native_mesg_num;
+        self.native_field_num = field_description.native_field_num;
     }
 }
 
@@ -36,8 +93,8 @@ impl fmt::Display for DataFieldAttributes {
             f,
             "{:22} SCL:{:5} OFF:{:5} UNIT:{:12}",
                 self.name,
-                self.scale.as_ref().map_or(&1, |v| v),
-                self.offset.as_ref().map_or(&0, |v| v),
+                self.scale.unwrap_or(1.),
+                self.offset.unwrap_or(0.),
                 self.units.as_ref().map_or("N/A", |n| &n[..]),
         )
     }
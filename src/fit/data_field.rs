@@ -1,19 +1,24 @@
 //! FIT data message field.
 
+use std::borrow::Cow;
 use std::fmt;
-use std::io::Cursor;
+use std::io::{Read, Seek};
 
 use binrw::BinRead;
 
 use crate::errors::FitError;
+use crate::profile::FitMessageType;
 
 use super::DataFieldAttributes;
-use super::DefinitionField;
+use super::physical::{self, PhysicalValue};
+use super::units::{self, UnitSystem};
+use super::{BaseTypeDefinition, DefinitionField};
 
 use super::value::Value;
 
 /// FIT data message field.
 #[derive(Debug, Clone, BinRead)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DataField {
     /// Field definition.
     pub definition: DefinitionField,
@@ -31,17 +36,87 @@ pub struct DataField {
 }
 
 impl DataField {
-    /// New FIT data message field. `cursor` represents the full FIT file data load,
-    /// but its offset must be at the start of a data message field.
+    /// New FIT data message field. `reader` represents the FIT file data load,
+    /// and its position must be at the start of a data message field.
     #[inline]
-    pub fn new(cursor: &mut Cursor<Vec<u8>>, field_def: &DefinitionField, arch: u8) -> Result<Self, FitError> {
+    pub fn new<R: Read + Seek>(reader: &mut R, field_def: &DefinitionField, arch: u8) -> Result<Self, FitError> {
         Ok(Self {
             definition: field_def.to_owned(),
             attributes: field_def.attributes.to_owned(),
-            data: Value::new(cursor, field_def, arch)?
+            data: Value::new(reader, field_def, arch)?
         })
     }
 
+    /// Synthetic `timestamp` field (field definition number `253`, `uint32`)
+    /// for data messages with a compressed timestamp header,
+    /// since these carry no timestamp field of their own.
+    pub(crate) fn timestamp(timestamp: u32) -> Self {
+        Self {
+            definition: DefinitionField {
+                field_def_no: 253,
+                size: 4,
+                base_type: BaseTypeDefinition::new(0x86), // uint32
+                attributes: None
+            },
+            attributes: None,
+            data: Value::Uint32(vec![timestamp])
+        }
+    }
+
+    /// Derived field expanded from a component field,
+    /// e.g. `enhanced_speed` from `speed` in `record/20`.
+    /// `data` must be an integer value, e.g. `Value::Uint16`.
+    pub(crate) fn component(field_def_no: u8, data: Value) -> Self {
+        let base_type = BaseTypeDefinition::new(data.base_type());
+        Self {
+            definition: DefinitionField {
+                field_def_no,
+                size: (base_type.base_len().unwrap_or(1) as usize * data.len()) as u8,
+                base_type,
+                attributes: None
+            },
+            attributes: None,
+            data
+        }
+    }
+
+    /// Native field merged from a developer field,
+    /// see `DataMessage::resolve_native()`.
+    pub(crate) fn native(
+        field_def_no: u8,
+        base_type: BaseTypeDefinition,
+        data: Value,
+        attributes: DataFieldAttributes
+    ) -> Self {
+        let base_len = base_type.base_len().unwrap_or(1) as usize;
+        Self {
+            definition: DefinitionField {
+                field_def_no,
+                size: (base_len * data.len()) as u8,
+                base_type,
+                attributes: None
+            },
+            attributes: Some(attributes),
+            data
+        }
+    }
+
+    /// Field with definition derived from `data`,
+    /// for data messages created when encoding,
+    /// e.g. field descriptions for developer data.
+    pub(crate) fn from_value(field_def_no: u8, data: Value) -> Self {
+        Self {
+            definition: DefinitionField {
+                field_def_no,
+                size: data.to_bytes(0).len() as u8,
+                base_type: BaseTypeDefinition::new(data.base_type()),
+                attributes: None
+            },
+            attributes: None,
+            data
+        }
+    }
+
     /// FIT field definition number.
     pub fn field_def_no(&self) -> u8 {
         self.definition.field_def_no
@@ -57,17 +132,17 @@ impl DataField {
     /// Returns field name if set.
     pub fn name(&self) -> Option<&str> {
         self.attributes.as_ref()
-            .map(|attr| attr.name.as_str())
+            .map(|attr| attr.name.as_ref())
     }
 
     /// Returns field scale if set.
-    pub fn scale(&self) -> Option<u32> {
+    pub fn scale(&self) -> Option<f64> {
         self.attributes.as_ref()
             .and_then(|attr| attr.scale)
     }
 
     /// Returns field offset if set.
-    pub fn offset(&self) -> Option<i32> {
+    pub fn offset(&self) -> Option<f64> {
         self.attributes.as_ref()
             .and_then(|attr| attr.offset)
     }
@@ -78,22 +153,118 @@ impl DataField {
             .and_then(|attr| attr.units.as_deref())
     }
 
+    /// Returns the name of the value for enum fields if set,
+    /// e.g. `cycling` for `sport`.
+    pub fn label(&self) -> Option<&'static str> {
+        self.attributes.as_ref()
+            .and_then(|attr| attr.label)
+    }
+
+    /// Returns developer data index for developer fields,
+    /// see `FieldDescriptionMessage`.
+    pub fn developer_data_index(&self) -> Option<u8> {
+        self.attributes.as_ref()
+            .and_then(|attr| attr.developer_data_index)
+    }
+
+    /// Returns application ID for developer fields, i.e. the application
+    /// that logged the field, e.g. a Connect IQ app.
+    /// See `DeveloperDataId`.
+    pub fn application_id(&self) -> Option<[u8; 16]> {
+        self.attributes.as_ref()
+            .and_then(|attr| attr.application_id)
+    }
+
+    /// Returns physical values, i.e. raw values with scale and offset
+    /// applied (`raw / scale - offset`), one per element.
+    /// Invalid values are `None`. Values in `semicircles`
+    /// are converted to decimal degrees.
+    /// Returns an empty `Vec` for strings.
+    pub fn values_f64(&self) -> Vec<Option<f64>> {
+        let (scale, offset, units) = (self.scale(), self.offset(), self.units());
+        (0 .. self.data.len())
+            .map(|i| self.data.as_f64_at(i)
+                .map(|raw| physical::to_physical(raw, scale, offset, units))
+            )
+            .collect()
+    }
+
+    /// Returns the first physical value,
+    /// or `None` if invalid. See `DataField::values_f64()`.
+    pub fn value_f64(&self) -> Option<f64> {
+        self.data.as_f64_at(0)
+            .map(|raw| physical::to_physical(raw, self.scale(), self.offset(), self.units()))
+    }
+
+    /// Returns physical values as for `DataField::values_f64()`,
+    /// with scale, offset and units from the FIT profile for
+    /// message type `global` if the field has no attributes,
+    /// i.e. if `Fit::augment()` has not been run.
+    pub(crate) fn profile_values_f64(&self, global: u16) -> Vec<Option<f64>> {
+        if self.attributes.is_some() {
+            return self.values_f64()
+        }
+        (0 .. self.data.len())
+            .map(|i| self.data.as_f64_at(i)
+                .map(|raw| FitMessageType::to_physical(global, self.field_def_no(), raw))
+            )
+            .collect()
+    }
+
+    /// Returns the first physical value, or `None` if invalid.
+    /// See `DataField::profile_values_f64()`.
+    pub(crate) fn profile_value_f64(&self, global: u16) -> Option<f64> {
+        self.profile_values_f64(global).first().copied().flatten()
+    }
+
+    /// Returns the first value as an integer type,
+    /// regardless of the logged base type.
+    /// Returns `None` for invalid values and if the value does not fit.
+    pub(crate) fn value_as<T: TryFrom<u64>>(&self) -> Option<T> {
+        self.data.as_u64()
+            .and_then(|n| T::try_from(n).ok())
+    }
+
+    /// Returns physical values together with their units,
+    /// or `None` for strings and if all values are invalid.
+    pub fn physical(&self) -> Option<PhysicalValue<'_>> {
+        let values = self.values_f64();
+        if values.iter().all(Option::is_none) {
+            return None
+        }
+        Some(PhysicalValue {
+            values,
+            units: physical::physical_units(self.units()),
+            name: self.name(),
+        })
+    }
+
+    /// Returns a wrapper for displaying physical values in `system`,
+    /// e.g. `enhanced_speed 18 km/h` for `UnitSystem::Metric`.
+    /// The `Display` implementation for `DataField` shows raw values.
+    pub fn display(&self, system: UnitSystem) -> DataFieldDisplay<'_> {
+        DataFieldDisplay {
+            field: self,
+            system,
+        }
+    }
+
     /// Set field name.
     pub fn set_name(&mut self, name: &str) {
         self.init_attr();
         self.attributes.as_mut()
-            .map(|attr| attr.name = name.to_owned());
+            .map(|attr| attr.name = Cow::Owned(name.to_owned()));
     }
 
     /// Set field scale.
-    pub fn set_scale(&mut self, scale: Option<u32>) {
+    pub fn set_scale(&mut self, scale: Option<f64>) {
         self.init_attr();
         self.attributes.as_mut()
             .map(|attr| attr.scale = scale);
     }
 
     /// Set field offset.
-    pub fn set_offset(&mut self, offset: Option<i32>) {
+    pub fn set_offset(&mut self, offset: Option<f64>) {
         self.init_attr();
         self.attributes.as_mut()
             .map(|attr| attr.offset = offset);
@@ -103,7 +274,15 @@ impl DataField {
     pub fn set_units(&mut self, units: Option<&str>) {
         self.init_attr();
         self.attributes.as_mut()
-            .map(|attr| attr.units = units.map(String::from));
+            .map(|attr| attr.units = units.map(|u| Cow::Owned(u.to_owned())));
+    }
+
+    /// Set name of the value for enum fields.
+    pub fn set_label(&mut self, label: Option<&'static str>) {
+        self.init_attr();
+        if let Some(attr) = self.attributes.as_mut() {
+            attr.label = label;
+        }
     }
 }
 
@@ -111,13 +290,57 @@ impl fmt::Display for DataField {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "{:4} {:22} SCL:{:5} OFF:{:5} UNIT:{:12} {:?}",
+            "{:4} {:22} SCL:{:5} OFF:{:5} UNIT:{:12} ",
             self.field_def_no(),
             self.name().map_or("UNKNOWN_FIELD", |n| &n[..]),
-            self.scale().as_ref().map_or(&1, |v| v),
-            self.offset().as_ref().map_or(&0, |v| v),
+            self.scale().unwrap_or(1.),
+            self.offset().unwrap_or(0.),
             self.units().map_or("N/A", |n| &n[..]),
-            self.data, // bad display for fields with large arrays, e.g. 3d sensor data
-        )
+        )?;
+        // bad display for fields with large arrays, e.g. 3d sensor data
+        match self.label() {
+            Some(label) => write!(f, "{label} ({:?})", self.data),
+            None => write!(f, "{:?}", self.data),
+        }
+    }
+}
+
+/// Displays a data field with physical values
+/// converted to a `UnitSystem`, see `DataField::display()`.
+/// Enum fields show the name of the value if set,
+/// and fields without physical values, such as strings,
+/// show raw values.
+#[derive(Debug, Clone, Copy)]
+pub struct DataFieldDisplay<'a> {
+    field: &'a DataField,
+    system: UnitSystem,
+}
+
+impl fmt::Display for DataFieldDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:4} {:22} ",
+            self.field.field_def_no(),
+            self.field.name().unwrap_or("UNKNOWN_FIELD"),
+        )?;
+        if let Some(label) = self.field.label() {
+            return write!(f, "{label}")
+        }
+        let value = match self.field.physical() {
+            Some(v) => v.to_system(self.system),
+            None => return write!(f, "{:?}", self.field.data)
+        };
+        let values = value.values.iter()
+            .map(|v| v.map_or("N/A".to_owned(), |n| units::round(n).to_string()))
+            .collect::<Vec<_>>();
+        match values.len() {
+            1 => write!(f, "{}", values[0])?,
+            _ => write!(f, "[{}]", values.join(", "))?,
+        }
+        match value.units {
+            Some(u) => write!(f, " {u}"),
+            None => Ok(())
+        }
     }
-}
\ No newline at end of file
+}
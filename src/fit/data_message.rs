@@ -1,8 +1,9 @@
 //! FIT data message.
 
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::fmt;
-use std::io::Cursor;
+use std::io::{Read, Seek};
 
 use binrw::BinRead;
 
@@ -10,8 +11,11 @@ use crate::errors::FitError;
 
 use super::DefinitionMessage;
 use super::data_field::DataField;
+use super::native::{self, NativePrecedence};
+use super::physical::PhysicalValue;
 
 #[derive(Debug, Default, Clone, BinRead)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[br(import(field_count: usize, dev_field_count: usize))]
 /// FIT data message. Contains raw data.
 pub struct DataMessage {
@@ -21,7 +25,7 @@ pub struct DataMessage {
     /// Name in FIT SDK `Profile.xlsx`.
     /// Optionally set after initial parse.
     #[br(ignore)]
-    pub name: Option<String>,
+    pub name: Option<Cow<'static, str>>,
     /// Data fields.
     #[br(count = field_count)]
     pub fields: Vec<DataField>,
@@ -30,7 +34,12 @@ pub struct DataMessage {
     pub dev_fields: Vec<DataField>,
     /// Message index.
     #[br(default)]
-    pub index: usize // slight performance decrease (50ms -> 52ms for large.fit)
+    pub index: usize, // slight performance decrease (50ms -> 52ms for large.fit)
+    /// Index of the definition message the data message
+    /// was parsed with, in `Fit.definitions`.
+    /// `None` for data messages not parsed from FIT-data.
+    #[br(default)]
+    pub definition: Option<usize>,
 }
 
 impl DataMessage {
@@ -43,7 +52,7 @@ impl DataMessage {
 
     pub fn with_name(self, name: &str) -> Self {
         Self {
-            name: Some(name.to_owned()),
+            name: Some(Cow::Owned(name.to_owned())),
             ..self
         }
     }
@@ -52,21 +61,21 @@ impl DataMessage {
         self.index = index;
     }
 
-    /// New FIT data message. `cursor` represents the full FIT file data load,
-    /// but its offset must be at the start of a data message.
-    pub fn parse(
-        cursor: &mut Cursor<Vec<u8>>,
+    /// New FIT data message. `reader` represents the FIT file data load,
+    /// and its position must be at the start of a data message.
+    pub fn parse<R: Read + Seek>(
+        reader: &mut R,
         definition: &DefinitionMessage,
     ) -> Result<Self, FitError> {
         
         let arch = definition.architecture;
         
         let fields = definition.fields.iter()
-            .map(|def| DataField::new(cursor, def, arch)) // slightly slower than direct init
+            .map(|def| DataField::new(reader, def, arch)) // slightly slower than direct init
             .collect::<Result<Vec<DataField>, FitError>>()?;
 
         let dev_fields = definition.dev_fields.iter()
-            .map(|dev_def| DataField::new(cursor, dev_def, arch)) // slightly slower than direct init
+            .map(|dev_def| DataField::new(reader, dev_def, arch)) // slightly slower than direct init
             .collect::<Result<Vec<DataField>, FitError>>()?;
 
         Ok(Self {
@@ -75,15 +84,71 @@ impl DataMessage {
             fields,
             dev_fields,
             index: usize::default(),
+            definition: Some(definition.index),
         })
     }
 
+    /// Returns the value of the `timestamp` field
+    /// (field definition number `253`) if present.
+    pub fn timestamp(&self) -> Option<u32> {
+        self.fields.iter()
+            .find(|f| f.field_def_no() == 253)
+            .and_then(|f| f.data.as_ref().into())
+    }
+
+    /// Returns physical values for the field or developer field
+    /// named `name`, with scale and offset applied,
+    /// e.g. `enhanced_altitude` in meters.
+    /// Requires names to be set, see `Fit::augment()`.
+    /// Returns `None` if there is no such field, if the field is a string,
+    /// or if all values are invalid. See `DataField::values_f64()`.
+    pub fn get(&self, name: &str) -> Option<PhysicalValue<'_>> {
+        self.fields.iter()
+            .chain(self.dev_fields.iter())
+            .find(|f| f.name() == Some(name))
+            .and_then(|f| f.physical())
+    }
+
+    /// Merges developer fields that map to a native field,
+    /// via `native_mesg_num` and `native_field_num` in the field description,
+    /// into `fields`, e.g. running power from a Connect IQ app as `record.power`.
+    /// Raw values are converted to the scale and offset of the native field.
+    /// Developer fields are kept in `dev_fields`.
+    ///
+    /// Merged fields keep developer data index and application ID as provenance,
+    /// see `DataField::application_id()`. `precedence` decides which value
+    /// to keep if the native field is logged as well.
+    pub fn resolve_native(&mut self, precedence: NativePrecedence) {
+        native::resolve(self, precedence)
+    }
+
+    /// Adds a synthetic `timestamp` field (field definition number `253`),
+    /// for data messages with a compressed timestamp header.
+    /// Does nothing if a `timestamp` field already exists.
+    pub(crate) fn with_timestamp(&mut self, timestamp: u32) {
+        if !self.fields.iter().any(|f| f.field_def_no() == 253) {
+            self.fields.insert(0, DataField::timestamp(timestamp));
+        }
+    }
+
+    /// Returns error for a required field that could not be assigned.
+    /// `FitError::InvalidFieldValue` if the field exists but only contains
+    /// invalid values, `FitError::ErrorAssigningField` otherwise.
+    pub(crate) fn field_error(&self, field_def_no: u8) -> FitError {
+        let invalid = self.fields.iter()
+            .any(|f| f.field_def_no() == field_def_no && f.data.is_invalid());
+        match invalid {
+            true => FitError::InvalidFieldValue{global: self.global, field_def_no},
+            false => FitError::ErrorAssigningField{global: self.global, field_def_no}
+        }
+    }
+
     /// Returns name/message type if set,
     /// and defaults to `UNKNOWN_TYPE_<ID>` if not.
     pub fn name(&self) -> String {
         self.name
             .as_ref()
-            .map_or(format!("UNKNOWN_TYPE_{}", self.global), |u| u.to_owned())
+            .map_or(format!("UNKNOWN_TYPE_{}", self.global), |u| u.to_string())
     }
 }
 
@@ -93,7 +158,7 @@ impl fmt::Display for DataMessage {
             f,
             "Global: {} | {}",
             self.global,
-            self.name.as_ref().map_or("UNKNOWN_TYPE", |n| n),
+            self.name.as_deref().unwrap_or("UNKNOWN_TYPE"),
         )?;
         for fld in self.fields.iter() {
             writeln!(f, "      {}", fld)?;
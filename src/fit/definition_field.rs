@@ -7,6 +7,7 @@ use crate::Fit;
 use crate::{errors::FitError, types::FieldDescriptionMessage};
 
 #[derive(Debug, Copy, Clone, BinRead)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BaseTypeDefinition(u8);
 
 impl BaseTypeDefinition {
@@ -15,6 +16,12 @@ impl BaseTypeDefinition {
         Self(def)
     }
 
+    /// Returns the base type byte as defined,
+    /// including endian ability and reserved bits.
+    pub fn as_u8(&self) -> u8 {
+        self.0
+    }
+
     /// Returns `false` for single byte type data (e.g. `u8`).
     /// Returns `true` for multi-byte data (e.g. `i32`).
     pub fn endian_ability(&self) -> bool {
@@ -63,6 +70,7 @@ impl BaseTypeDefinition {
 
 /// FIT message field definition.
 #[derive(Debug, Clone, BinRead)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DefinitionField {
     /// Field definition number.
     pub field_def_no: u8,
@@ -6,11 +6,19 @@ use std::collections::HashMap;
 use binrw::BinRead;
 use rayon::prelude::{IntoParallelRefMutIterator, ParallelIterator};
 
-use crate::FieldDescriptionMessage;
+use crate::{DeveloperDataId, FieldDescriptionMessage};
 use super::DefinitionField;
 
 /// FIT definition message.
+/// Definitions are listed in the order encountered in `Fit.definitions`,
+/// and data messages reference the definition they were parsed with
+/// via `DataMessage.definition`.
+///
+/// Developer fields are augmented with the field description (`206`)
+/// if one is available, in which case `base_type` holds the FIT base type,
+/// and the developer data index is set in `attributes`.
 #[derive(Debug, Default, BinRead, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[br(import(contains_developer_fields: bool))]
 pub struct DefinitionMessage {
     /// Reserved. Byte 0.
@@ -31,6 +39,12 @@ pub struct DefinitionMessage {
     /// Developer definition fields (3 bytes/each). Optional.
     #[br(count = _number_of_devfields)]
     pub dev_fields: Vec<DefinitionField>,
+    /// Local ID (0-15) the definition was assigned to.
+    #[br(ignore)]
+    pub local: u8,
+    /// Definition index, i.e. position in the definition stream.
+    #[br(ignore)]
+    pub index: usize,
 }
 
 impl DefinitionMessage {
@@ -46,6 +60,27 @@ impl DefinitionMessage {
             });
     }
 
+    /// Links developer fields to the application that logged them,
+    /// via the developer data index set by `with_field_descriptions()`.
+    pub fn with_developer_data_ids(
+        &mut self,
+        developer_data_ids: &HashMap<u8, DeveloperDataId>
+    ) {
+        self.dev_fields.iter_mut()
+            .filter_map(|field| field.attributes.as_mut())
+            .for_each(|attr| {
+                attr.application_id = attr.developer_data_index
+                    .and_then(|i| developer_data_ids.get(&i))
+                    .and_then(|id| id.application_id);
+            });
+    }
+
+    /// Returns `true` if data messages for this definition
+    /// are big-endian.
+    pub fn is_big_endian(&self) -> bool {
+        self.architecture == 1
+    }
+
     /// Returns the size of the data in bytes the definition describes,
     /// excluding the 1 byte header.
     pub fn data_size(&self) -> i64 {
@@ -0,0 +1,65 @@
+//! Diagnostics for lenient parsing.
+//!
+//! In lenient mode each problem encountered while parsing is recorded
+//! as a `Diagnostic`, instead of aborting the parse.
+
+use std::fmt;
+
+use crate::errors::FitError;
+
+/// Kind of problem encountered while parsing in lenient mode.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum DiagnosticKind {
+    /// Data message with a local ID that has no definition.
+    /// Skipped, then parsing resumed at the next plausible message header.
+    UnknownDefinition,
+    /// Invalid or corrupt message.
+    /// Skipped, then parsing resumed at the next plausible message header.
+    InvalidMessage,
+    /// Field description message (FIT global ID `206`) that could not be parsed.
+    /// The data message is kept, but the developer fields it describes will lack attributes.
+    InvalidFieldDescription,
+    /// Data ended mid-message, e.g. a FIT-file that was not closed properly.
+    /// Parsing stopped.
+    Truncated,
+}
+
+impl fmt::Display for DiagnosticKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DiagnosticKind::UnknownDefinition => write!(f, "unknown definition"),
+            DiagnosticKind::InvalidMessage => write!(f, "invalid message"),
+            DiagnosticKind::InvalidFieldDescription => write!(f, "invalid field description"),
+            DiagnosticKind::Truncated => write!(f, "truncated"),
+        }
+    }
+}
+
+/// A problem encountered while parsing in lenient mode.
+#[derive(Debug)]
+pub struct Diagnostic {
+    /// Byte offset for the start of the message.
+    pub offset: u64,
+    /// Local ID (0-15) in the message header, if it could be read.
+    pub local: Option<u8>,
+    /// Kind of problem.
+    pub kind: DiagnosticKind,
+    /// The error that caused the diagnostic.
+    pub error: FitError,
+    /// Number of bytes skipped before parsing resumed.
+    pub skipped: u64,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "OFFSET {} | LOCAL {} | {} ({}), skipped {} bytes",
+            self.offset,
+            self.local.map_or("N/A".to_owned(), |l| l.to_string()),
+            self.kind,
+            self.error,
+            self.skipped
+        )
+    }
+}
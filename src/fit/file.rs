@@ -0,0 +1,422 @@
+//! FIT file kinds, as specified by `type` in `file_id/0`.
+//!
+//! The FIT SDK lists which messages each kind of file,
+//! e.g. activity, course or workout, is meant to contain.
+//! `FitFile` dispatches on `file_id.type` into a view for each kind,
+//! with accessors for those messages.
+
+use std::ops::Range;
+
+use crate::{
+    types::{
+        Activity,
+        DeviceInfo,
+        EventMessage,
+        FileId,
+        Hrv,
+        Lap,
+        Session,
+    },
+    FileType,
+    Fit,
+    FitError,
+};
+
+use super::DataMessage;
+
+/// Data messages for a single FIT file, i.e. all of `Fit.records`,
+/// or a segment for chained FIT files.
+#[derive(Debug, Clone)]
+pub struct FileView<'a> {
+    fit: &'a Fit,
+    range: Range<usize>,
+    file_type: u8,
+}
+
+impl<'a> FileView<'a> {
+    /// Returns the parsed FIT file.
+    pub fn fit(&self) -> &'a Fit {
+        self.fit
+    }
+
+    /// Returns slice range for the file's data messages in `Fit.records`.
+    pub fn range(&self) -> Range<usize> {
+        self.range.to_owned()
+    }
+
+    /// Returns raw `type` in `file_id/0`.
+    pub fn file_type_raw(&self) -> u8 {
+        self.file_type
+    }
+
+    /// Returns `type` in `file_id/0`, or `None` for
+    /// manufacturer specific types and types not in the FIT profile.
+    pub fn file_type(&self) -> Option<FileType> {
+        FileType::from_u8(self.file_type)
+    }
+
+    /// Returns `FileId` (`file_id/0`).
+    pub fn file_id(&self) -> Result<FileId, FitError> {
+        FileId::from_fit(self.fit, Some(&self.range))
+    }
+
+    /// Returns all data messages with FIT global ID `global`,
+    /// in logging order.
+    pub fn messages(&self, global: u16) -> Vec<&'a DataMessage> {
+        self.fit.records[self.range()].iter()
+            .filter(|rec| rec.global == global)
+            .collect()
+    }
+
+    /// Returns the first data message with FIT global ID `global`.
+    fn message(&self, global: u16) -> Option<&'a DataMessage> {
+        self.fit.records[self.range()].iter()
+            .find(|rec| rec.global == global)
+    }
+}
+
+/// FIT file, by `type` in `file_id/0`.
+#[derive(Debug, Clone)]
+pub enum FitFile<'a> {
+    /// `activity` (4), or `activity_summary` (20).
+    Activity(ActivityFile<'a>),
+    /// `course` (6).
+    Course(CourseFile<'a>),
+    /// `workout` (5).
+    Workout(WorkoutFile<'a>),
+    /// `settings` (2).
+    Settings(SettingsFile<'a>),
+    /// `monitoring_a` (15), `monitoring_b` (32), or `monitoring_daily` (28).
+    Monitoring(MonitoringFile<'a>),
+    /// `segment` (34).
+    Segment(SegmentFile<'a>),
+    /// `totals` (10).
+    Totals(TotalsFile<'a>),
+    /// `weight` (9).
+    Weight(WeightFile<'a>),
+    /// Any other type, e.g. `device`, `sport`, `schedules`,
+    /// or manufacturer specific types.
+    Other(FileView<'a>),
+}
+
+impl<'a> FitFile<'a> {
+    /// Dispatches on `type` in `file_id/0`, for the data messages
+    /// in `range`, or all data messages if `range` is `None`.
+    /// Use the range for a `FitSegment` for chained FIT files.
+    ///
+    /// Only `file_id.type` is required, since e.g. course files
+    /// may not log a serial number.
+    pub fn new(fit: &'a Fit, range: Option<&Range<usize>>) -> Result<Self, FitError> {
+        let global = 0_u16;
+
+        let range = range.cloned().unwrap_or(0 .. fit.len());
+
+        let file_id = fit.records[range.to_owned()].iter() // should be very early so linear is fine
+            .find(|rec| rec.global == global)
+            .ok_or_else(|| FitError::ErrorParsingMessage(global))?;
+        let file_type: u8 = file_id.fields.iter()
+            .find(|f| f.field_def_no() == 0)
+            .and_then(|f| f.value_as())
+            .ok_or_else(|| file_id.field_error(0))?;
+
+        let view = FileView {
+            fit,
+            range,
+            file_type,
+        };
+
+        let file = match view.file_type() {
+            Some(FileType::Activity | FileType::ActivitySummary) => Self::Activity(ActivityFile{view}),
+            Some(FileType::Course) => Self::Course(CourseFile{view}),
+            Some(FileType::Workout) => Self::Workout(WorkoutFile{view}),
+            Some(FileType::Settings) => Self::Settings(SettingsFile{view}),
+            Some(FileType::MonitoringA | FileType::MonitoringB | FileType::MonitoringDaily) => Self::Monitoring(MonitoringFile{view}),
+            Some(FileType::Segment) => Self::Segment(SegmentFile{view}),
+            Some(FileType::Totals) => Self::Totals(TotalsFile{view}),
+            Some(FileType::Weight) => Self::Weight(WeightFile{view}),
+            _ => Self::Other(view),
+        };
+
+        Ok(file)
+    }
+
+    /// Returns the data messages for the file,
+    /// regardless of kind.
+    pub fn view(&self) -> &FileView<'a> {
+        match self {
+            Self::Activity(f) => &f.view,
+            Self::Course(f) => &f.view,
+            Self::Workout(f) => &f.view,
+            Self::Settings(f) => &f.view,
+            Self::Monitoring(f) => &f.view,
+            Self::Segment(f) => &f.view,
+            Self::Totals(f) => &f.view,
+            Self::Weight(f) => &f.view,
+            Self::Other(view) => view,
+        }
+    }
+
+    /// Returns `type` in `file_id/0`, see `FileView::file_type()`.
+    pub fn file_type(&self) -> Option<FileType> {
+        self.view().file_type()
+    }
+}
+
+/// Activity file, e.g. a recorded run or ride.
+#[derive(Debug, Clone)]
+pub struct ActivityFile<'a> {
+    view: FileView<'a>,
+}
+
+impl<'a> ActivityFile<'a> {
+    /// Returns the data messages for the file.
+    pub fn view(&self) -> &FileView<'a> {
+        &self.view
+    }
+
+    /// `activity/34`. Not logged for `activity_summary`.
+    pub fn activity(&self) -> Result<Activity, FitError> {
+        Activity::from_fit(self.view.fit, Some(&self.view.range))
+    }
+
+    /// `session/18`
+    pub fn sessions(&self) -> Result<Vec<Session>, FitError> {
+        Session::from_fit(self.view.fit, Some(&self.view.range))
+    }
+
+    /// `lap/19`
+    pub fn laps(&self) -> Result<Vec<Lap>, FitError> {
+        Lap::from_fit(self.view.fit, Some(&self.view.range))
+    }
+
+    /// `length/101`, for pool swims.
+    pub fn lengths(&self) -> Vec<&'a DataMessage> {
+        self.view.messages(101)
+    }
+
+    /// `record/20`
+    pub fn records(&self) -> Vec<&'a DataMessage> {
+        self.view.messages(20)
+    }
+
+    /// `event/21`
+    pub fn events(&self) -> Result<Vec<EventMessage>, FitError> {
+        EventMessage::from_fit(self.view.fit, Some(&self.view.range))
+    }
+
+    /// `device_info/23`
+    pub fn device_info(&self) -> Result<Vec<DeviceInfo>, FitError> {
+        DeviceInfo::from_fit(self.view.fit, Some(&self.view.range))
+    }
+
+    /// `hrv/78`
+    pub fn hrv(&self) -> Result<Vec<Hrv>, FitError> {
+        Hrv::from_fit(self.view.fit, Some(&self.view.range))
+    }
+}
+
+/// Course file, i.e. a planned route.
+#[derive(Debug, Clone)]
+pub struct CourseFile<'a> {
+    view: FileView<'a>,
+}
+
+impl<'a> CourseFile<'a> {
+    /// Returns the data messages for the file.
+    pub fn view(&self) -> &FileView<'a> {
+        &self.view
+    }
+
+    /// `course/31`, with e.g. name and sport.
+    pub fn course(&self) -> Option<&'a DataMessage> {
+        self.view.message(31)
+    }
+
+    /// `lap/19`
+    pub fn laps(&self) -> Result<Vec<Lap>, FitError> {
+        Lap::from_fit(self.view.fit, Some(&self.view.range))
+    }
+
+    /// `record/20`, i.e. the route.
+    pub fn records(&self) -> Vec<&'a DataMessage> {
+        self.view.messages(20)
+    }
+
+    /// `event/21`
+    pub fn events(&self) -> Result<Vec<EventMessage>, FitError> {
+        EventMessage::from_fit(self.view.fit, Some(&self.view.range))
+    }
+
+    /// `course_point/32`, e.g. turns and summits.
+    pub fn course_points(&self) -> Vec<&'a DataMessage> {
+        self.view.messages(32)
+    }
+}
+
+/// Workout file, i.e. a structured training session.
+#[derive(Debug, Clone)]
+pub struct WorkoutFile<'a> {
+    view: FileView<'a>,
+}
+
+impl<'a> WorkoutFile<'a> {
+    /// Returns the data messages for the file.
+    pub fn view(&self) -> &FileView<'a> {
+        &self.view
+    }
+
+    /// `workout/26`, with e.g. name and sport.
+    pub fn workout(&self) -> Option<&'a DataMessage> {
+        self.view.message(26)
+    }
+
+    /// `workout_step/27`
+    pub fn workout_steps(&self) -> Vec<&'a DataMessage> {
+        self.view.messages(27)
+    }
+}
+
+/// Settings file, i.e. user and device settings.
+#[derive(Debug, Clone)]
+pub struct SettingsFile<'a> {
+    view: FileView<'a>,
+}
+
+impl<'a> SettingsFile<'a> {
+    /// Returns the data messages for the file.
+    pub fn view(&self) -> &FileView<'a> {
+        &self.view
+    }
+
+    /// `user_profile/3`
+    pub fn user_profile(&self) -> Option<&'a DataMessage> {
+        self.view.message(3)
+    }
+
+    /// `hrm_profile/4`
+    pub fn hrm_profiles(&self) -> Vec<&'a DataMessage> {
+        self.view.messages(4)
+    }
+
+    /// `sdm_profile/5`
+    pub fn sdm_profiles(&self) -> Vec<&'a DataMessage> {
+        self.view.messages(5)
+    }
+
+    /// `bike_profile/6`
+    pub fn bike_profiles(&self) -> Vec<&'a DataMessage> {
+        self.view.messages(6)
+    }
+
+    /// `device_settings/2`
+    pub fn device_settings(&self) -> Option<&'a DataMessage> {
+        self.view.message(2)
+    }
+}
+
+/// Monitoring file, i.e. all-day activity tracking.
+#[derive(Debug, Clone)]
+pub struct MonitoringFile<'a> {
+    view: FileView<'a>,
+}
+
+impl<'a> MonitoringFile<'a> {
+    /// Returns the data messages for the file.
+    pub fn view(&self) -> &FileView<'a> {
+        &self.view
+    }
+
+    /// `monitoring_info/103`, with e.g. local time.
+    pub fn monitoring_info(&self) -> Option<&'a DataMessage> {
+        self.view.message(103)
+    }
+
+    /// `monitoring/55`
+    pub fn monitoring(&self) -> Vec<&'a DataMessage> {
+        self.view.messages(55)
+    }
+
+    /// `device_info/23`
+    pub fn device_info(&self) -> Result<Vec<DeviceInfo>, FitError> {
+        DeviceInfo::from_fit(self.view.fit, Some(&self.view.range))
+    }
+}
+
+/// Segment file, i.e. a route section with a leaderboard.
+#[derive(Debug, Clone)]
+pub struct SegmentFile<'a> {
+    view: FileView<'a>,
+}
+
+impl<'a> SegmentFile<'a> {
+    /// Returns the data messages for the file.
+    pub fn view(&self) -> &FileView<'a> {
+        &self.view
+    }
+
+    /// `segment_id/148`, with e.g. name and sport.
+    pub fn segment_id(&self) -> Option<&'a DataMessage> {
+        self.view.message(148)
+    }
+
+    /// `segment_leaderboard_entry/149`
+    pub fn leaderboard_entries(&self) -> Vec<&'a DataMessage> {
+        self.view.messages(149)
+    }
+
+    /// `segment_lap/142`
+    pub fn segment_laps(&self) -> Vec<&'a DataMessage> {
+        self.view.messages(142)
+    }
+
+    /// `segment_point/150`, i.e. the route.
+    pub fn segment_points(&self) -> Vec<&'a DataMessage> {
+        self.view.messages(150)
+    }
+}
+
+/// Totals file, i.e. lifetime totals per sport.
+#[derive(Debug, Clone)]
+pub struct TotalsFile<'a> {
+    view: FileView<'a>,
+}
+
+impl<'a> TotalsFile<'a> {
+    /// Returns the data messages for the file.
+    pub fn view(&self) -> &FileView<'a> {
+        &self.view
+    }
+
+    /// `totals/33`
+    pub fn totals(&self) -> Vec<&'a DataMessage> {
+        self.view.messages(33)
+    }
+}
+
+/// Weight file, i.e. weight scale measurements.
+#[derive(Debug, Clone)]
+pub struct WeightFile<'a> {
+    view: FileView<'a>,
+}
+
+impl<'a> WeightFile<'a> {
+    /// Returns the data messages for the file.
+    pub fn view(&self) -> &FileView<'a> {
+        &self.view
+    }
+
+    /// `user_profile/3`
+    pub fn user_profile(&self) -> Option<&'a DataMessage> {
+        self.view.message(3)
+    }
+
+    /// `weight_scale/30`
+    pub fn weight_scale(&self) -> Vec<&'a DataMessage> {
+        self.view.messages(30)
+    }
+
+    /// `device_info/23`
+    pub fn device_info(&self) -> Result<Vec<DeviceInfo>, FitError> {
+        DeviceInfo::from_fit(self.view.fit, Some(&self.view.range))
+    }
+}
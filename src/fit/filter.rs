@@ -0,0 +1,131 @@
+//! Parse-time filtering of data messages.
+//!
+//! Field description messages (global ID 206) and developer data ID messages
+//! (global ID 207) are always processed internally, so developer fields
+//! in the data messages that are kept are named and scaled correctly,
+//! but they are only returned if the filter keeps them.
+//!
+//! ```rs
+//! use fit_rs::{Fit, FitFilter};
+//! use std::path::Path;
+//!
+//! fn main() -> Result<(), fit_rs::FitError> {
+//!     let path = Path::new("FITFILE.fit");
+//!
+//!     // Only keep file_id (0) and record (20) messages.
+//!     let fit = Fit::parse(path, [0, 20], false)?;
+//!
+//!     // Only keep timestamp (253) and heart_rate (3) in record (20) messages.
+//!     let filter = FitFilter::fields(|global, field| global == 20 && [253, 3].contains(&field));
+//!     let fit = Fit::parse(path, filter, false)?;
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use std::{collections::HashSet, fmt, sync::Arc};
+
+use super::DataMessage;
+
+/// Predicate on FIT global ID and field definition number.
+pub type FieldPredicate = Arc<dyn Fn(u16, u8) -> bool + Send + Sync>;
+
+/// Parse-time filter for data messages.
+#[derive(Clone, Default)]
+pub enum FitFilter {
+    /// Keep all data messages.
+    #[default]
+    All,
+    /// Keep data messages with any of the specified FIT global IDs.
+    Global(HashSet<u16>),
+    /// Keep data fields for which the predicate returns `true`
+    /// when called with FIT global ID and field definition number.
+    /// Data messages with no remaining fields are discarded.
+    /// Developer fields are kept for all remaining data messages.
+    Fields(FieldPredicate),
+}
+
+impl FitFilter {
+    /// Keep data messages with any of the specified FIT global IDs.
+    pub fn globals<I: IntoIterator<Item = u16>>(globals: I) -> Self {
+        Self::Global(globals.into_iter().collect())
+    }
+
+    /// Keep data fields for which `predicate` returns `true`
+    /// when called with FIT global ID and field definition number.
+    pub fn fields<F>(predicate: F) -> Self
+    where
+        F: Fn(u16, u8) -> bool + Send + Sync + 'static
+    {
+        Self::Fields(Arc::new(predicate))
+    }
+
+    /// Returns `true` if all data messages are kept.
+    pub fn is_all(&self) -> bool {
+        matches!(self, Self::All)
+    }
+
+    /// Applies filter to a data message. Returns `None`
+    /// if the message should be discarded.
+    pub(crate) fn apply(&self, mut data: DataMessage) -> Option<DataMessage> {
+        match self {
+            Self::All => Some(data),
+            Self::Global(globals) => globals.contains(&data.global)
+                .then_some(data),
+            Self::Fields(predicate) => {
+                let global = data.global;
+                data.fields.retain(|field| predicate(global, field.definition.field_def_no));
+                (!data.fields.is_empty()).then_some(data)
+            }
+        }
+    }
+}
+
+impl fmt::Debug for FitFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::All => write!(f, "All"),
+            Self::Global(globals) => f.debug_tuple("Global").field(globals).finish(),
+            Self::Fields(_) => write!(f, "Fields(..)"),
+        }
+    }
+}
+
+impl From<Option<u16>> for FitFilter {
+    fn from(global: Option<u16>) -> Self {
+        match global {
+            Some(g) => Self::globals([g]),
+            None => Self::All
+        }
+    }
+}
+
+impl From<u16> for FitFilter {
+    fn from(global: u16) -> Self {
+        Self::globals([global])
+    }
+}
+
+impl<const N: usize> From<[u16; N]> for FitFilter {
+    fn from(globals: [u16; N]) -> Self {
+        Self::globals(globals)
+    }
+}
+
+impl From<&[u16]> for FitFilter {
+    fn from(globals: &[u16]) -> Self {
+        Self::globals(globals.iter().copied())
+    }
+}
+
+impl From<Vec<u16>> for FitFilter {
+    fn from(globals: Vec<u16>) -> Self {
+        Self::globals(globals)
+    }
+}
+
+impl From<HashSet<u16>> for FitFilter {
+    fn from(globals: HashSet<u16>) -> Self {
+        Self::Global(globals)
+    }
+}
@@ -23,8 +23,10 @@
 //! ```
 
 use std::{
+    borrow::Cow,
+    fs::File,
     path::{PathBuf, Path},
-    io::Cursor,
+    io::{BufWriter, Read, Write},
     collections::HashMap,
     ops::Range
 };
@@ -33,37 +35,80 @@ use rayon::{
     iter::{IntoParallelRefIterator, ParallelIterator},
     prelude::IntoParallelRefMutIterator
 };
-use time::PrimitiveDateTime;
+use time::{OffsetDateTime, PrimitiveDateTime, UtcOffset};
 
 use crate::{
     constants::FIT_DEFAULT_DATETIME,
     errors::FitError,
-    fit::{message::MessageType, Message},
     profile::message_type::FitMessageType, types::{
-        FieldDescriptionMessage, FileId, FitPoint, SensorType
+        FileId, FitPoint, SensorType
     },
+    Activity,
     CameraEvent,
+    CameraEventType,
+    DeveloperDataId,
+    DeviceInfo,
+    EventMessage,
     FitSession,
     GpsMetadata,
+    Hrv,
+    Lap,
     Record,
+    Session,
     SensorData,
+    TimeModel,
     TimestampCorrelation
 };
 use super::{
     fit_header::FitHeader,
+    CrcStatus,
+    FitCrc,
+    FitFile,
+    FitFilter,
+    FitReader,
+    FitSegment,
+    FitWriter,
+    DataFieldAttributes,
+    DataField,
     DataMessage,
     DefinitionMessage,
+    Diagnostic,
+    NativePrecedence,
+    TimestampMode,
 };
 
+/// Messages with a `local_timestamp` field,
+/// as `(FIT global ID, field definition number)`.
+const LOCAL_TIMESTAMP_FIELDS: [(u16, u8); 3] = [
+    (34, 5),  // activity
+    (103, 0), // monitoring_info
+    (162, 3), // timestamp_correlation
+];
+
 /// Fit core data struct, containing parsed FIT data, header etc.
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Fit {
     /// Path to parsed FIT-file
     pub path: PathBuf,
-    /// The header, containing data size etc
+    /// The header, containing data size etc.
+    /// For chained FIT files this is the header
+    /// for the first one, see `segments`.
     pub header: FitHeader,
+    /// Header and file CRC status.
+    /// For chained FIT files this is the status
+    /// for the first one, see `segments`.
+    pub crc: FitCrc,
+    /// Header, CRC status, and data message range
+    /// for each FIT file, if several are chained.
+    /// Contains a single segment for regular FIT files.
+    pub segments: Vec<FitSegment>,
     /// The actual data in logging/chronological order
     pub records: Vec<DataMessage>,
+    /// Definition messages in the order encountered,
+    /// with local ID and architecture. Referenced by
+    /// `DataMessage.definition`.
+    pub definitions: Vec<DefinitionMessage>,
     pub index: HashMap<String, Range<usize>> // optionally populated post-parse
 }
 
@@ -77,110 +122,184 @@ impl Fit {
         Self::parse(path, None, true)
     }
 
+    /// Parse FIT-data in full and reject files
+    /// where the header CRC or the file CRC
+    /// does not match. Absent CRCs are accepted.
+    pub fn new_checked(path: &Path) -> Result<Self, FitError> {
+        let fit = Self::parse(path, None, false)?;
+        fit.check_crc()?;
+        Ok(fit)
+    }
+
     /// Parse FIT-data.
     ///
     /// Most data types will need further processing. E.g.
     /// FIT stores coordinates as semicircles, not decimal degrees.
     ///
-    /// Optionally filter on specified `global` ID
-    /// while parsing. Developer data is not supported
-    /// when filtering at parse time.
-    pub fn parse(path: &Path, global: Option<u16>, debug: bool) -> Result<Self, FitError> {
-
-        let mut cursor = Self::cursor(path)?;
-        let len = cursor.get_ref().len();
+    /// Chained FIT files, i.e. several complete FIT files
+    /// concatenated into one, are parsed in full
+    /// with each one listed in `Fit.segments`.
+    ///
+    /// Optionally filter data messages while parsing,
+    /// on one or more FIT global IDs, e.g. `Some(20)` or `[0, 20]`,
+    /// or on fields via `FitFilter::fields()`. Use `None` to keep everything.
+    /// Developer data is supported when filtering,
+    /// since field descriptions are always processed.
+    pub fn parse(path: &Path, filter: impl Into<FitFilter>, debug: bool) -> Result<Self, FitError> {
+        Self::parse_reader(path, filter.into(), debug, false)
+            .map(|(fit, _)| fit)
+    }
 
-        let fitheader = FitHeader::new(&mut cursor)?;
+    /// Parse FIT-data in lenient mode, for e.g. corrupt FIT-files,
+    /// or FIT-files that were not closed properly due to a dead battery.
+    /// Returns the recovered data together with a list of problems
+    /// encountered.
+    ///
+    /// Instead of returning an error, each problem is recorded
+    /// as a `Diagnostic` with byte offset, local ID, and error kind, after which parsing resumes:
+    /// - Data messages without a definition and invalid messages are skipped,
+    ///   and parsing resumes at the next plausible message header.
+    /// - Truncated data stops parsing without an error.
+    ///
+    /// An error is only returned if the file can not be read.
+    pub fn parse_lenient(path: &Path, filter: impl Into<FitFilter>, debug: bool) -> Result<(Self, Vec<Diagnostic>), FitError> {
+        Self::parse_reader(path, filter.into(), debug, true)
+    }
 
-        if debug {println!("{fitheader:#?}")}
+    fn parse_reader(
+        path: &Path,
+        filter: FitFilter,
+        debug: bool,
+        lenient: bool
+    ) -> Result<(Self, Vec<Diagnostic>), FitError> {
+        let bytes = std::fs::read(path)?;
 
-        let data_size = fitheader.data_size(len) as u64;
+        let mut reader = FitReader::new(bytes.as_slice())
+            .with_len(bytes.len() as u64)
+            .with_filter(filter)
+            .with_debug(debug)
+            .with_lenient(lenient)
+            .with_definitions(true);
 
-        // Simple incremental index for data messages,
-        // that can be used to sort in e.g. chronological order,
-        // even after filtering on type
-        let mut data_index = 0;
+        let records = reader.by_ref()
+            .collect::<Result<Vec<DataMessage>, FitError>>()?;
 
-        let mut definitions: HashMap<u8, DefinitionMessage> = HashMap::new();
-        let mut data_messages: Vec<DataMessage> = Vec::new();
-        let mut field_descriptions: HashMap<(u8, u8), FieldDescriptionMessage> = HashMap::new();
+        let mut fit = Self::from_segments(records, reader.segments().to_vec(), reader.definitions().to_vec());
+        fit.path = path.to_owned();
 
-        while cursor.position() < data_size {
+        Ok((fit, reader.into_diagnostics()))
+    }
 
-            if debug {print!("OFFSET {} | ", cursor.position())}
+    /// Parse FIT-data in full from any `std::io::Read` source,
+    /// e.g. stdin or an in-memory byte slice.
+    /// `Fit.path` is left empty.
+    ///
+    /// Use `FitReader` to iterate over data messages
+    /// without reading everything into memory.
+    pub fn from_reader<R: Read>(reader: R) -> Result<Self, FitError> {
+        let mut reader = FitReader::new(reader)
+            .with_definitions(true);
 
-            // Parses message in full.
-            // Due to parsing data messages, this is slightly slower than,
-            // e.g. reading only header and only if data message flag is set parse data.
-            // It is cleaner however.
-            let message = Message::parse(&mut cursor, &definitions)?;
-            let id = message.id();
+        let records = reader.by_ref()
+            .collect::<Result<Vec<DataMessage>, FitError>>()?;
 
-            match message.message_type() {
+        let definitions = reader.definitions().to_vec();
 
-                // Definition message
-                MessageType::Definition(mut definition) => {
+        Ok(Self::from_segments(records, reader.into_segments(), definitions))
+    }
 
-                    // Add field descriptions for developer data
-                    definition.with_field_descriptions(&field_descriptions);
+    /// Write FIT-file to `path`, see `Fit::write_to()`.
+    pub fn write(&self, path: &Path) -> Result<(), FitError> {
+        self.write_to(BufWriter::new(File::create(path)?))?;
+        Ok(())
+    }
 
-                    if debug {println!("{definition:#?}")}
+    /// Encode `Fit.records` as FIT-data to any `std::io::Write` destination,
+    /// and return the writer. Chained FIT files are written as one FIT file
+    /// per segment, each with the header settings of the original.
+    /// Data messages are encoded with the definitions in `Fit.definitions`
+    /// where possible, and developer data IDs (`207`) are written in full
+    /// from those in the same segment. See `FitWriter` for limitations.
+    pub fn write_to<W: Write>(&self, writer: W) -> Result<W, FitError> {
+        if self.segments.is_empty() {
+            let mut fit_writer = FitWriter::new(writer)
+                .with_definitions(self.definitions.iter().cloned())
+                .with_developer_data_ids(self.writable_developer_data_ids(0 .. self.len()));
+            fit_writer.write_all(self.records.iter())?;
+            return fit_writer.finish()
+        }
 
-                    definitions.insert(
-                        id,
-                        definition
-                    );
-                },
+        let mut writer = writer;
+        for segment in self.segments.iter() {
+            let mut fit_writer = FitWriter::new(writer)
+                .with_header(segment.header)
+                .with_definitions(self.definitions.iter().cloned())
+                .with_developer_data_ids(self.writable_developer_data_ids(segment.range()));
+            fit_writer.write_all(self.records[segment.range()].iter())?;
+            writer = fit_writer.finish()?;
+        }
 
-                // Data message
-                MessageType::Data(mut data) => {
+        Ok(writer)
+    }
 
-                    // Ignore storing message if FIT global ID
-                    // does not correspond to the one optionally specified.
-                    if let Some(g) = global {
-                        if data.global != g {
-                            continue;
-                        }
-                    }
+    /// Developer data IDs in `range` for `Fit::write_to()`,
+    /// skipping any without a developer data index.
+    fn writable_developer_data_ids(&self, range: Range<usize>) -> Vec<DeveloperDataId> {
+        self.records[range].iter()
+            .filter(|rec| rec.global == 207)
+            .filter_map(|rec| DeveloperDataId::new(rec).ok())
+            .collect()
+    }
 
-                    // Set index to preserve chronological order if filtering etc
-                    data.index = data_index;
-
-                    if debug {println!("{data:#?}")}
-
-                    // Parse and store custom developer definitions
-                    if data.global == 206 {
-                        let field_descr = FieldDescriptionMessage::new(&data)?;
-                        // Require both field_definition_number and developer_data_index
-                        // to create a unique key since third parties are not
-                        // always using this correctly, sometimes causing ID collisions
-                        field_descriptions.insert(
-                            (field_descr.field_definition_number, field_descr.developer_data_index),
-                            field_descr,
-                        );
-                    }
+    fn from_segments(
+        records: Vec<DataMessage>,
+        segments: Vec<FitSegment>,
+        definitions: Vec<DefinitionMessage>
+    ) -> Self {
+        // Header and CRC for the first FIT file,
+        // chained FIT files are listed in `segments`.
+        let (header, crc) = segments.first()
+            .map(|s| (s.header, s.crc))
+            .unwrap_or_default();
+
+        Fit{
+            path: PathBuf::new(),
+            header,
+            crc,
+            segments,
+            records,
+            definitions,
+            index: HashMap::new()
+        }
+    }
 
-                    data_messages.push(data);
+    /// Returns the definition message `message` was parsed with,
+    /// i.e. the fields the device defined, their order, and the local ID.
+    pub fn definition(&self, message: &DataMessage) -> Option<&DefinitionMessage> {
+        message.definition
+            .and_then(|i| self.definitions.get(i))
+    }
 
-                    data_index += 1; // data message index
-                }
+    /// Returns `true` if the FIT file consists of
+    /// several chained FIT files.
+    pub fn is_chained(&self) -> bool {
+        self.segments.len() > 1
+    }
 
-                _ => ()
+    /// Returns an error if the header CRC
+    /// or the file CRC does not match
+    /// for any of the chained FIT files.
+    /// Absent CRCs are accepted.
+    pub fn check_crc(&self) -> Result<(), FitError> {
+        for segment in self.segments.iter() {
+            if let CrcStatus::Mismatch{expected, got} = segment.crc.header {
+                return Err(FitError::HeaderCrcMismatch{expected, got})
+            }
+            if let CrcStatus::Mismatch{expected, got} = segment.crc.file {
+                return Err(FitError::FileCrcMismatch{expected, got})
             }
         }
-
-        Ok(Fit{
-            path: path.to_owned(),
-            header: fitheader,
-            records: data_messages,
-            index: HashMap::new()
-        })
-    }
-
-    /// Read FIT-file into a `std::io::Cursor<Vec<u8>>`.
-    fn cursor(path: &Path) -> std::io::Result<Cursor<Vec<u8>>> {
-        let bytes = std::fs::read(&path)?;
-        Ok(Cursor::new(bytes))
+        Ok(())
     }
 
     /// Returns `true` if bit at `position` is set. For checking FIT message headers.
@@ -222,11 +341,11 @@ impl Fit {
         camera_events.iter() // can not use par_iter, since assigning outer variable
             .for_each(|event|
                 // Find start of session
-                if event.camera_event_type == 0 {
+                if event.event_type() == Some(CameraEventType::VideoStart) {
                     uuid = Some(event.camera_file_uuid.to_owned());
                     start = Some(event.index);
                 // Find end of session
-                } else if start.is_some() && event.camera_event_type == 2 {
+                } else if start.is_some() && event.event_type() == Some(CameraEventType::VideoEnd) {
                     if let (Some(u), Some(s)) = (uuid.take(), start.take()) {
                         // index.insert(u, s .. event.index + 1); // shouldn't be +1???
                         index.insert(u, s .. event.index);
@@ -289,15 +408,15 @@ impl Fit {
             // Logged chronologically so
             // the first encountered event should
             // never be e.g. 2 (session end).
-            match evt.camera_event_type {
+            match evt.event_type() {
                 // 0 = recording session start
-                0 => {
+                Some(CameraEventType::VideoStart) => {
                     session.path = self.path.to_owned();
                     session.start = evt.index;
                     session.uuid.push(evt.camera_file_uuid.to_owned());
                 },
                 // 2 = recording session end
-                2 => {
+                Some(CameraEventType::VideoEnd) => {
                     session.end = evt.index;
                     session.uuid.dedup(); // works since logged chronologically
                     sessions.push(session);
@@ -326,11 +445,37 @@ impl Fit {
         grouped_records
     }
 
-    /// VIRB only.
+    /// Returns the timestamp mode for the FIT file,
+    /// based on the first `timestamp` (field definition number `253`)
+    /// logged, or `None` if no timestamps are logged.
+    /// VIRB logs relative timestamps, watches and bike computers
+    /// absolute ones. See `TimestampMode`.
+    ///
+    /// `timestamp_correlation/162` is ignored, since its `timestamp`
+    /// is always absolute.
+    pub fn timestamp_mode(&self) -> Option<TimestampMode> {
+        self.records.iter()
+            .filter(|m| m.global != 162)
+            .find_map(|m| m.timestamp())
+            .map(TimestampMode::from_timestamp)
+    }
+
+    /// Derives start time of FIT-file
+    /// with time offset `offset` (e.g. time zone),
+    /// so that adding a timestamp yields absolute time.
+    /// Use `UtcOffset::UTC` for UTC, or `Fit::utc_offset()`
+    /// for the local offset logged in the FIT file.
     ///
-    /// Derives start time of FIT-file via `timestamp_correlation/162`
-    /// with option time offset in hours (e.g. time zone).
-    /// If no `timestamp_correlation/162` exists in input
+    /// VIRB and watches log timestamps differently. VIRB logs a relative timestamp
+    /// from start of FIT-file that has to be augmented by a correlation value logged
+    /// at GPS satellite lock in `timestamp_correlation/162`.
+    /// Watches and bike computers log absolute timestamps directly,
+    /// in which case Garmin's FIT base start time 1989-12-31T00:00:00.000
+    /// is returned. See `Fit::timestamp_mode()`.
+    /// Only the first `timestamp_correlation/162` is used, i.e. a constant offset.
+    /// See `Fit::time_model()` for correcting clock drift in long recordings.
+    ///
+    /// For relative timestamps, if no `timestamp_correlation/162` exists in input
     /// `ParseError::NoDataForMessageType(162)` will be returned.
     /// If a required field cannot be assigned, its field definition number
     /// will be returned in `ParseError::ErrorAssigningFieldValue(FIELD_NO)`.
@@ -338,13 +483,15 @@ impl Fit {
     /// `default_on_error` ensures a time for first logged message can be returned.
     /// If `default_on_error = true` and no `timestamp_correlation`/`162` can be found,
     /// Garmin's FIT base start time is used: 1989-12-31T00:00:00.000.
-    /// VIRB and watches log timestamps differently. VIRB logs a relative timestamp
-    /// from start of FIT-file that has to be augmented by a correlation value logged
-    /// at GPS satellite lock. Watches seem to log the full value directly and not need
-    /// the correlation value.
-    pub fn t0(&self, offset_hours: i64, default_on_error: bool) -> Result<PrimitiveDateTime, FitError> {
+    pub fn t0(&self, offset: UtcOffset, default_on_error: bool) -> Result<PrimitiveDateTime, FitError> {
         // let fit_datetime = Self::basetime();
         // let fit_datetime = FIT_DEFAULT_DATETIME;
+        let offset = time::Duration::seconds(offset.whole_seconds() as i64);
+
+        if self.timestamp_mode() == Some(TimestampMode::Absolute) {
+            return Ok(FIT_DEFAULT_DATETIME + offset)
+        }
+
         let tc = match TimestampCorrelation::from_fit(self) {
             Ok(t) => t,
             Err(err) => match default_on_error {
@@ -355,33 +502,197 @@ impl Fit {
 
         Ok(
             FIT_DEFAULT_DATETIME
-            + time::Duration::hours(offset_hours) // TODO 220808 change offset to proper timezone?
+            + offset
             + time::Duration::seconds(tc.timestamp as i64 - tc.system_timestamp as i64)
             + time::Duration::milliseconds(tc.timestamp_ms as i64 - tc.system_timestamp_ms as i64),
         )
     }
 
+    /// Returns absolute UTC time for `message`, from its `timestamp`
+    /// (field definition number `253`), and `timestamp_ms` if listed
+    /// for the message type in Profile.xlsx.
+    ///
+    /// Absolute and relative timestamps are detected
+    /// per value, see `TimestampMode`. Relative timestamps,
+    /// as logged by VIRB, are converted via a `TimeModel` built from
+    /// all `timestamp_correlation/162`, which corrects for clock drift.
+    /// The time model is built for each call, so use `Fit::time_model()`
+    /// once instead when converting many relative timestamps.
+    ///
+    /// Returns `FitError::ErrorAssigningField` or `FitError::InvalidFieldValue`
+    /// if `message` has no valid timestamp.
+    pub fn datetime(&self, message: &DataMessage) -> Result<OffsetDateTime, FitError> {
+        let timestamp = match message.timestamp() {
+            Some(t) => t,
+            None => return Err(message.field_error(253))
+        };
+
+        // `timestamp_ms` has different field definition numbers
+        // depending on message type
+        let timestamp_ms: u16 = FitMessageType::get(message.global)
+            .and_then(|mt| mt.fields.iter().find(|f| f.name == "timestamp_ms"))
+            .and_then(|ft| message.fields.iter().find(|f| f.field_def_no() == ft.field_def_no))
+            .and_then(|f| f.data.as_ref().into())
+            .unwrap_or(0);
+
+        let ms = timestamp as i64 * 1000 + timestamp_ms as i64;
+
+        match TimestampMode::from_timestamp(timestamp) {
+            TimestampMode::Absolute => Ok(
+                (FIT_DEFAULT_DATETIME + time::Duration::milliseconds(ms)).assume_utc()
+            ),
+            TimestampMode::Relative => self.time_model(false)?
+                .datetime(ms)
+                .ok_or(FitError::ErrorParsingMessage(162))
+        }
+    }
+
+    /// Returns local time for `message`, i.e. `Fit::datetime()`
+    /// with the local time offset at the time of `message`,
+    /// see `Fit::utc_offset_at()`. Uses UTC if no local
+    /// time offset is logged.
+    pub fn local_datetime(&self, message: &DataMessage) -> Result<OffsetDateTime, FitError> {
+        let datetime = self.datetime(message)?;
+        let offset = self.utc_offset_at(datetime).unwrap_or(UtcOffset::UTC);
+        Ok(datetime.to_offset(offset))
+    }
+
+    /// Returns local time offsets logged in the FIT file,
+    /// as `(UTC time, offset)` in logging order.
+    /// Consecutive identical offsets are only listed once,
+    /// so several offsets mean the time zone changed,
+    /// e.g. for a daylight saving time change.
+    ///
+    /// Offsets are derived from `local_timestamp` versus `timestamp`
+    /// in `activity/34`, `monitoring_info/103`, and `timestamp_correlation/162`,
+    /// and rounded to the nearest 15 minutes.
+    pub fn utc_offsets(&self) -> Vec<(OffsetDateTime, UtcOffset)> {
+        let mut offsets: Vec<(OffsetDateTime, UtcOffset)> = Vec::new();
+        for message in self.records.iter() {
+            let field_def_no = match LOCAL_TIMESTAMP_FIELDS.iter().find(|(g, _)| *g == message.global) {
+                Some((_, n)) => *n,
+                None => continue
+            };
+            let (timestamp, local) = match (message.timestamp(), Self::local_timestamp(message, field_def_no)) {
+                (Some(t), Some(l)) if TimestampMode::from_timestamp(t) == TimestampMode::Absolute => (t, l),
+                _ => continue
+            };
+            // Time zones are whole quarters of an hour
+            let seconds = ((local as i64 - timestamp as i64) as f64 / 900.).round() as i32 * 900;
+            let offset = match UtcOffset::from_whole_seconds(seconds) {
+                Ok(o) => o,
+                Err(_) => continue
+            };
+            if offsets.last().map(|(_, o)| *o) != Some(offset) {
+                let datetime = (FIT_DEFAULT_DATETIME + time::Duration::seconds(timestamp as i64)).assume_utc();
+                offsets.push((datetime, offset));
+            }
+        }
+        offsets
+    }
+
+    /// Returns the first local time offset logged in the FIT file,
+    /// or `None` if none is logged. See `Fit::utc_offsets()`.
+    pub fn utc_offset(&self) -> Option<UtcOffset> {
+        self.utc_offsets().first()
+            .map(|(_, offset)| *offset)
+    }
+
+    /// Returns the local time offset in effect at `datetime`,
+    /// i.e. the last one logged before `datetime`, or the first one
+    /// if `datetime` precedes all. Returns `None` if none is logged.
+    /// See `Fit::utc_offsets()`.
+    pub fn utc_offset_at(&self, datetime: OffsetDateTime) -> Option<UtcOffset> {
+        let offsets = self.utc_offsets();
+        offsets.iter()
+            .rev()
+            .find(|(dt, _)| *dt <= datetime)
+            .or(offsets.first())
+            .map(|(_, offset)| *offset)
+    }
+
+    /// Returns the value of `local_timestamp`
+    /// (field definition number `field_def_no`) in `message`.
+    fn local_timestamp(message: &DataMessage, field_def_no: u8) -> Option<u32> {
+        message.fields.iter()
+            .find(|f| f.field_def_no() == field_def_no)
+            .and_then(|f| f.data.as_ref().into())
+    }
+
+    /// VIRB only.
+    ///
+    /// Returns a piecewise-linear time model for converting
+    /// relative timestamps to absolute UTC time, built from all
+    /// `timestamp_correlation/162`, and `gps_metadata/160`
+    /// UTC timestamps if `gps` is set.
+    /// Also exposes clock drift, see `TimeModel::drift()`.
+    pub fn time_model(&self, gps: bool) -> Result<TimeModel, FitError> {
+        TimeModel::from_fit(self, gps)
+    }
+
     /// Looks up name, units, scale and offset for most
     /// message types documented in Profile.xlsx.
-    /// Message types with complex fields are not supported.
+    /// Component fields are expanded while parsing,
+    /// so derived fields, e.g. `enhanced_speed`, are augmented as well.
+    /// Dynamic subfields are resolved against the other fields
+    /// in the same data message, e.g. `product` in `device_info/23`
+    /// becomes `garmin_product` if `manufacturer` is Garmin.
+    /// Enum fields are labelled with the name of their value,
+    /// e.g. `cycling` for `sport`, if the type is listed in Profile.xlsx.
+    ///
+    /// Names, units, and labels reference the static profile
+    /// rather than being copied into each data field.
     pub fn augment(&mut self) {
         self.records.par_iter_mut().for_each(|m| {
-            let mt = FitMessageType::get(m.global);
-            m.name = Some(mt.name.to_owned());
-            m.fields.par_iter_mut().for_each(|f| {
-                // Only augmenting standard fields,
-                // since dev fields should already have
-                // name, units, scale, offset set.
-                if let Some(fld_descr) = mt.fields.get(&f.field_def_no()) {
-                    f.set_name(&fld_descr.name);
-                    f.set_units(fld_descr.units.as_deref());
-                    f.set_scale(fld_descr.scale);
-                    f.set_offset(fld_descr.offset);
+            let mt = match FitMessageType::get(m.global) {
+                Some(mt) => mt,
+                None => {
+                    m.name = Some(Cow::Owned(format!("UNKNOWN_TYPE_{}", m.global)));
+                    return
                 }
-            });
+            };
+            m.name = Some(Cow::Borrowed(mt.name));
+
+            // Only augmenting standard fields,
+            // since dev fields should already have
+            // name, units, scale, offset set.
+            for i in 0 .. m.fields.len() {
+                let fld_descr = match mt.field(m.fields[i].field_def_no()) {
+                    Some(f) => f,
+                    None => continue
+                };
+                let value = m.fields[i].data.as_u64();
+                // Subfields depend on sibling field values,
+                // which are not changed by augmenting.
+                let mut attributes = match fld_descr.subfield(&m.fields) {
+                    Some(sub) => DataFieldAttributes::profile(
+                        sub.name, sub.scale, sub.offset, sub.units,
+                        value.and_then(|v| sub.label(v))
+                    ),
+                    None => DataFieldAttributes::profile(
+                        fld_descr.name, fld_descr.scale, fld_descr.offset, fld_descr.units,
+                        value.and_then(|v| fld_descr.label(v))
+                    ),
+                };
+                // Keep provenance for developer fields merged into native fields
+                if let Some(existing) = m.fields[i].attributes.as_ref() {
+                    attributes.developer_data_index = existing.developer_data_index;
+                    attributes.application_id = existing.application_id;
+                }
+                m.fields[i].attributes = Some(attributes);
+            }
         });
     }
 
+    /// Merges developer fields that map to a native field into
+    /// the native field slots for all data messages, so that typed APIs,
+    /// such as `Fit::record()`, read them as native fields.
+    /// See `DataMessage::resolve_native()`.
+    pub fn resolve_native(&mut self, precedence: NativePrecedence) {
+        self.records.par_iter_mut()
+            .for_each(|m| m.resolve_native(precedence));
+    }
+
     /// Garmin VIRB only.
     ///
     /// Returns all `camera_event` messages in FIT-file.
@@ -421,6 +732,103 @@ impl Fit {
         FileId::from_fit(&self, range)
     }
 
+    /// Returns all `session` messages (`18`), i.e. the summary
+    /// for each sport, such as totals and averages for distance,
+    /// speed and heart rate, in physical units.
+    ///
+    /// Not to be confused with VIRB recording sessions, see `Fit::sessions()`.
+    pub fn sessions_summary(
+        &self,
+        range: Option<&Range<usize>>
+    ) -> Result<Vec<Session>, FitError> {
+        Session::from_fit(self, range)
+    }
+
+    /// Returns all `lap` messages (`19`), i.e. the summary for each lap,
+    /// such as totals and averages for distance, speed and heart rate,
+    /// in physical units.
+    pub fn laps(
+        &self,
+        range: Option<&Range<usize>>
+    ) -> Result<Vec<Lap>, FitError> {
+        Lap::from_fit(self, range)
+    }
+
+    /// Returns `Activity` (`activity/34`), i.e. the summary
+    /// for the whole activity, such as number of sessions and local time.
+    pub fn activity(
+        &self,
+        range: Option<&Range<usize>>
+    ) -> Result<Activity, FitError> {
+        Activity::from_fit(self, range)
+    }
+
+    /// Returns all `event` messages (`21`), e.g. timer start/stop,
+    /// gear changes and power down, with `data` decoded
+    /// according to the event.
+    pub fn events(
+        &self,
+        range: Option<&Range<usize>>
+    ) -> Result<Vec<EventMessage>, FitError> {
+        EventMessage::from_fit(self, range)
+    }
+
+    /// Returns all `device_info` messages (`23`), i.e. the recording device
+    /// and connected sensors, with battery status and sensor type.
+    pub fn device_info(
+        &self,
+        range: Option<&Range<usize>>
+    ) -> Result<Vec<DeviceInfo>, FitError> {
+        DeviceInfo::from_fit(self, range)
+    }
+
+    /// Returns all `hrv` messages (`78`), i.e. RR-intervals in seconds.
+    pub fn hrv(
+        &self,
+        range: Option<&Range<usize>>
+    ) -> Result<Vec<Hrv>, FitError> {
+        Hrv::from_fit(self, range)
+    }
+
+    /// Returns the kind of FIT file, by `type` in `file_id/0`,
+    /// with accessors for the messages that kind of file
+    /// is meant to contain. For chained FIT files,
+    /// this is the first file, see `Fit::files()`.
+    pub fn file(&self) -> Result<FitFile<'_>, FitError> {
+        let range = self.segments.first().map(|s| s.range());
+        FitFile::new(self, range.as_ref())
+    }
+
+    /// Returns the kind of each FIT file in a chained FIT file,
+    /// see `Fit::file()`.
+    pub fn files(&self) -> Result<Vec<FitFile<'_>>, FitError> {
+        self.segments.iter()
+            .map(|s| FitFile::new(self, Some(&s.range)))
+            .collect()
+    }
+
+    /// Returns all `developer_data_id` messages (`207`), which identify
+    /// the application, e.g. a Connect IQ app, for each developer data index.
+    /// Developer fields are linked to their application while parsing,
+    /// see `DataField::application_id()`.
+    pub fn developer_data_ids(
+        &self,
+        range: Option<&Range<usize>>
+    ) -> Result<Vec<DeveloperDataId>, FitError> {
+        DeveloperDataId::from_fit(self, range.cloned())
+    }
+
+    /// Returns developer fields logged by the application with ID
+    /// `application_id`, together with the data message for each,
+    /// in logging order. Tells apart fields from different applications
+    /// that share a field name. See `DeveloperDataId`.
+    pub fn developer_fields_by_app(&self, application_id: &[u8; 16]) -> Vec<(&DataMessage, &DataField)> {
+        self.records.iter()
+            .flat_map(|m| m.dev_fields.iter().map(move |f| (m, f)))
+            .filter(|(_, f)| f.application_id().as_ref() == Some(application_id))
+            .collect()
+    }
+
     /// VIRB only.
     ///
     /// VIRB logs a relative timestamp counting from
@@ -450,7 +858,7 @@ impl Fit {
     /// - `gps_accuracy`, field definition number `31`
     ///
     /// If `no_fail` is set to `true`, records with errors
-    /// relating to missing fields will be silently discarded.
+    /// relating to missing or invalid fields will be silently discarded.
     pub fn record(
         &self,
         range: Option<&Range<usize>>,
@@ -1,13 +1,16 @@
 //! FIT file level header.
 
-use std::io::Cursor;
+use std::io::{Read, Seek};
 
 use binrw::{BinRead, BinReaderExt};
 
 use crate::errors::FitError;
 
+use super::CrcStatus;
+
 /// FIT file level header.
 #[derive(Debug, Copy, Clone, Default, BinRead)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FitHeader {
     /// Byte 0: size of header
     pub headersize: u8,
@@ -19,7 +22,8 @@ pub struct FitHeader {
     pub datasize: u32,
     /// Bytes 8-11: Ascii for .FIT
     pub dotfit: [u8; 4],
-    /// Bytes 12, 13: CRC, optional. CRC check not implemented.
+    /// Bytes 12, 13: CRC, optional.
+    /// Verified via `FitHeader::header_crc()`.
     #[br(default)]
     pub crc: Option<u16>,
 }
@@ -27,16 +31,26 @@ pub struct FitHeader {
 impl FitHeader {
     /// Read FIT header with optional CRC value.
     /// Valid sizes are 12 or 14 bytes.
-    pub fn new(cursor: &mut Cursor<Vec<u8>>) -> Result<Self, FitError> {
-        let mut hdr: FitHeader = cursor.read_ne()?;
+    pub fn new<R: Read + Seek>(reader: &mut R) -> Result<Self, FitError> {
+        let mut hdr: FitHeader = reader.read_le()?;
 
         if hdr.headersize == 14 {
-            hdr.crc = Some(cursor.read_ne::<u16>()?);
+            hdr.crc = Some(reader.read_le::<u16>()?);
         }
 
         Ok(hdr)
     }
 
+    /// Returns `true` if `bytes` starts with what looks like
+    /// a FIT header, i.e. a header size of 12 or 14 bytes,
+    /// followed by `.FIT` at bytes 8-11.
+    /// Used for detecting chained FIT files.
+    pub fn is_header(bytes: &[u8]) -> bool {
+        bytes.len() >= 12
+            && matches!(bytes[0], 12 | 14)
+            && &bytes[8..12] == b".FIT"
+    }
+
     /// Derive data size, including for corrupt or truncated FIT-files.
     /// `len` is size of FitFile in bytes.
     /// Returns `FitHeader.datasize` if no issues exist.
@@ -53,6 +67,35 @@ impl FitHeader {
         }
     }
 
+    /// Verifies the optional header CRC.
+    /// `bytes` must start at the FIT header.
+    ///
+    /// Returns `CrcStatus::Absent` for 12 byte headers
+    /// and for a stored CRC of `0x0000`, which
+    /// signals that the CRC was not computed.
+    pub fn header_crc(&self, bytes: &[u8]) -> CrcStatus {
+        match self.crc {
+            Some(crc) if crc != 0 && bytes.len() >= 12 => CrcStatus::new(&bytes[..12], Some(crc)),
+            _ => CrcStatus::Absent
+        }
+    }
+
+    /// Verifies the trailing 2 byte file CRC,
+    /// which follows the data section and covers
+    /// both header and data.
+    /// `bytes` must start at the FIT header.
+    ///
+    /// Returns `CrcStatus::Absent` if the file is truncated,
+    /// or reports a data size of 0.
+    pub fn file_crc(&self, bytes: &[u8]) -> CrcStatus {
+        let end = self.headersize as usize + self.datasize as usize;
+        if self.datasize == 0 || bytes.len() < end + 2 {
+            return CrcStatus::Absent
+        }
+        let stored = u16::from_le_bytes([bytes[end], bytes[end + 1]]);
+        CrcStatus::new(&bytes[..end], Some(stored))
+    }
+
     pub fn dotfit(&self) -> String{
         self.dotfit.iter()
             .map(|n| *n as char)
@@ -1,4 +1,4 @@
-use std::{io::Cursor, collections::HashMap};
+use std::{io::{Read, Seek}, collections::HashMap};
 
 use binrw::{BinReaderExt, BinRead};
 
@@ -42,24 +42,44 @@ pub struct Message {
 impl Message {
     /// Parse FIT message. Results in either a definition message,
     /// or a data message (requires definition).
-    pub fn parse(
-        cursor: &mut Cursor<Vec<u8>>,
-        definitions: &HashMap<u8, DefinitionMessage>
+    ///
+    /// `last_timestamp` is the last full timestamp (field `253`)
+    /// encountered and is updated for each data message
+    /// that contains one. Data messages with a compressed timestamp
+    /// header are resolved against it and get a synthetic
+    /// `timestamp` field added.
+    pub fn parse<R: Read + Seek>(
+        reader: &mut R,
+        definitions: &HashMap<u8, DefinitionMessage>,
+        last_timestamp: &mut Option<u32>,
     ) -> Result<Self, FitError> {
 
-        let header: MessageHeader = cursor.read_ne()?;
+        let header: MessageHeader = reader.read_ne()?;
         let id = header.id();
-        let pos = cursor.position();
+        let pos = reader.stream_position()?;
 
         let message = match header.kind() {
-            Kind::Definition => MessageType::read_ne_args(cursor, (header,))?,
+            Kind::Definition => MessageType::read_ne_args(reader, (header,))?,
             Kind::Data => {
                 let definition = definitions.get(&id)
                     .ok_or_else(|| FitError::UnknownDefinition {local: id, offset: pos})?;
-                let data_message = DataMessage::parse(
-                    cursor,
+                let mut data_message = DataMessage::parse(
+                    reader,
                     definition,
                 )?;
+
+                if header.comp_time().is_some() {
+                    let last = last_timestamp
+                        .ok_or_else(|| FitError::NoReferenceTimestamp {local: id, offset: pos})?;
+                    // always Some for compressed timestamp headers
+                    if let Some(timestamp) = header.timestamp(last) {
+                        data_message.with_timestamp(timestamp);
+                        *last_timestamp = Some(timestamp);
+                    }
+                } else if let Some(timestamp) = data_message.timestamp() {
+                    *last_timestamp = Some(timestamp);
+                }
+
                 MessageType::Data(data_message)
             },
         };
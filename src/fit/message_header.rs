@@ -20,15 +20,15 @@
 //! ```
 //! Bit idx    7 6 5 4 3 2 1 0
 //! Header   | 1 x x x x x x x |
-//!            | | | | └┬────┘
-//!            | | | |  |
-//!            | | | |  ╰- Local ID, 0-15
-//!            | | | |     maps a data messge to the corresponding definition
-//!            | | | ╰- Reserved (?)
-//!            | | ╰- 1 = contains custom developer field definitions
-//!            | ╰--- 0 = Data, 1 = Definition
+//!            | └┬┘ └──┬──┘
+//!            |  |     |
+//!            |  |     ╰- Time offset in seconds, 0-31,
+//!            |  |        relative to last full timestamp
+//!            |  ╰- Local ID, 0-3
+//!            |     maps a data messge to the corresponding definition
 //!            ╰----- 1 = Compressed time stamp header,
 //!                       changes the rest of the header layout if set.
+//!                       Always a data message.
 //! ```
 
 use binrw::BinRead;
@@ -53,10 +53,21 @@ pub enum Kind {
 pub struct MessageHeader(u8);
 
 impl MessageHeader {
+    /// New message header from raw header byte.
+    pub fn new(header: u8) -> Self {
+        Self(header)
+    }
+
     /// Checks whether message is a definition
     /// or a data message.
     // pub fn kind(&self) -> MessageType {
+    ///
+    /// Compressed timestamp headers are always data messages,
+    /// since bit 6 is part of the local ID in that case.
     pub fn kind(&self) -> Kind {
+        if self.comp_time().is_some() {
+            return Kind::Data
+        }
         match Fit::bit_set(self.0, 6) {
             true => Kind::Definition,
             false => Kind::Data,
@@ -65,10 +76,8 @@ impl MessageHeader {
 
     /// FIT local ID (0-15) for matching
     /// definition messages with data message
-    /// during parse.
-    ///
-    /// Note that while `id()` supports compressed
-    /// timestamp headers the rest of `fit-rs` does not.
+    /// during parse. Compressed timestamp headers
+    /// only use local IDs 0-3.
     pub fn id(&self) -> u8 {
         // check for compressed time stamp header
         match self.comp_time() {
@@ -79,10 +88,9 @@ impl MessageHeader {
 
     /// Checks if header is a compressed
     /// time stamp header and returns the
-    /// value if so.
-    ///
-    /// Note that while `comp_time()` supports compressed
-    /// timestamp headers the rest of `fit-rs` does not.
+    /// value if so. The value is a 5-bit
+    /// time offset in seconds, relative to the last
+    /// full timestamp (field `253`) in the data stream.
     pub fn comp_time(&self) -> Option<u8> {
         match Fit::bit_set(self.0, 7) {
             true => Some(0b0001_1111 & self.0),
@@ -90,6 +98,23 @@ impl MessageHeader {
         }
     }
 
+    /// Derives the full timestamp for a compressed timestamp header
+    /// from the last full timestamp in the data stream.
+    /// Returns `None` for normal headers.
+    ///
+    /// The 5-bit time offset rolls over every 32 seconds, so if
+    /// the offset is smaller than the 5 least significant bits
+    /// of `last_timestamp` a rollover has occurred.
+    pub fn timestamp(&self, last_timestamp: u32) -> Option<u32> {
+        let offset = self.comp_time()? as u32;
+        let base = last_timestamp & !0x1F;
+        if offset >= last_timestamp & 0x1F {
+            Some(base.wrapping_add(offset))
+        } else {
+            Some(base.wrapping_add(offset).wrapping_add(0x20))
+        }
+    }
+
     /// Returns `true` if definition contains developer fields.
     /// Only relvant for definition messages and always returns `false`
     /// for data messages, since there is no flag for developer data in data
@@ -100,4 +125,51 @@ impl MessageHeader {
             Kind::Data => false
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Compressed timestamp header for local ID `local` and time offset `offset`.
+    fn compressed(local: u8, offset: u8) -> MessageHeader {
+        MessageHeader::new(0x80 | (local << 5) | (offset & 0x1F))
+    }
+
+    #[test]
+    fn timestamp_normal_header() {
+        assert_eq!(MessageHeader::new(0x01).timestamp(1000), None);
+        assert_eq!(MessageHeader::new(0x41).timestamp(1000), None);
+    }
+
+    #[test]
+    fn timestamp_no_rollover() {
+        // 1000 = 0x3E8, 5 least significant bits = 8
+        assert_eq!(compressed(1, 8).timestamp(1000), Some(1000));
+        assert_eq!(compressed(1, 11).timestamp(1000), Some(1003));
+        assert_eq!(compressed(1, 31).timestamp(1000), Some(1023));
+    }
+
+    #[test]
+    fn timestamp_rollover() {
+        // offset 1 < 8, so the 5-bit offset has rolled over
+        assert_eq!(compressed(1, 1).timestamp(1000), Some(1025));
+        assert_eq!(compressed(1, 7).timestamp(1000), Some(1031));
+    }
+
+    #[test]
+    fn timestamp_rollover_single_step() {
+        // 1023 = 0x3FF, offset 0x1F -> 0x00 is one second
+        assert_eq!(compressed(0, 0x00).timestamp(1023), Some(1024));
+        assert_eq!(compressed(0, 0x1F).timestamp(1023), Some(1023));
+    }
+
+    #[test]
+    fn compressed_header_local_id() {
+        let header = compressed(3, 0x1F);
+        assert_eq!(header.kind(), Kind::Data);
+        assert_eq!(header.id(), 3);
+        assert_eq!(header.comp_time(), Some(0x1F));
+        assert!(!header.dev());
+    }
+}
@@ -1,21 +1,55 @@
 pub mod fit;
+pub mod component;
+pub mod crc;
 pub mod fit_header;
 pub mod message_header;
 pub mod message;
 pub mod definition_message;
+pub mod diagnostic;
+pub mod file;
+pub mod filter;
 pub mod definition_field;
 pub mod data_attributes;
 pub mod data_message;
 pub mod data_field;
+pub mod native;
+pub mod physical;
+pub mod units;
+pub mod reader;
+pub mod segment;
+pub mod timestamp;
 pub mod value;
+pub mod writer;
 
 pub use fit::Fit;
+pub use crc::{crc16, CrcStatus, FitCrc};
 pub use fit_header::FitHeader;
 pub use message::Message;
 pub use message_header::{MessageHeader, Kind};
 pub use definition_field::{BaseTypeDefinition, DefinitionField};
 pub use definition_message::DefinitionMessage;
+pub use diagnostic::{Diagnostic, DiagnosticKind};
+pub use file::{
+    ActivityFile,
+    CourseFile,
+    FileView,
+    FitFile,
+    MonitoringFile,
+    SegmentFile,
+    SettingsFile,
+    TotalsFile,
+    WeightFile,
+    WorkoutFile
+};
+pub use filter::{FieldPredicate, FitFilter};
 pub use data_attributes::DataFieldAttributes;
-pub use data_field::DataField;
+pub use data_field::{DataField, DataFieldDisplay};
 pub use data_message::DataMessage;
-pub use value::Value;
\ No newline at end of file
+pub use native::NativePrecedence;
+pub use physical::PhysicalValue;
+pub use units::UnitSystem;
+pub use reader::FitReader;
+pub use segment::FitSegment;
+pub use timestamp::TimestampMode;
+pub use value::Value;
+pub use writer::FitWriter;
\ No newline at end of file
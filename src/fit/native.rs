@@ -0,0 +1,190 @@
+//! Native field mapping for developer fields.
+//!
+//! Field descriptions (global ID 206) may declare that a developer field
+//! is equivalent to a native field, via `native_mesg_num` and `native_field_num`,
+//! e.g. running power from a Connect IQ app as `record.power`.
+//! Resolving merges such developer fields into the native field slots,
+//! so that typed APIs read them as native fields.
+
+use crate::profile::{FitFieldType, FitMessageType};
+
+use super::{physical, BaseTypeDefinition, DataField, DataFieldAttributes, DataMessage, Value};
+
+/// Which value to keep if a data message contains both
+/// a native field and a developer field that maps to it.
+/// Native fields that only contain invalid values
+/// are always replaced.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NativePrecedence {
+    /// Keep the native value.
+    #[default]
+    Native,
+    /// Replace the native value with the developer value.
+    Developer,
+}
+
+/// Merges developer fields in `message` that map to a native field
+/// into `message.fields`. See `DataMessage::resolve_native()`.
+pub(crate) fn resolve(message: &mut DataMessage, precedence: NativePrecedence) {
+    let message_type = FitMessageType::get(message.global);
+
+    for j in 0 .. message.dev_fields.len() {
+        let dev = &message.dev_fields[j];
+        let field_def_no = match dev.attributes.as_ref() {
+            Some(attr) if attr.native_mesg_num.is_none_or(|g| g == message.global) => match attr.native_field_num {
+                Some(n) => n,
+                None => continue
+            },
+            _ => continue
+        };
+        if dev.data.is_invalid() {
+            continue
+        }
+
+        let existing = message.fields.iter()
+            .position(|f| f.field_def_no() == field_def_no);
+        if let Some(i) = existing {
+            if precedence == NativePrecedence::Native && !message.fields[i].data.is_invalid() {
+                continue
+            }
+        }
+
+        let native_type = message_type.and_then(|mt| mt.field(field_def_no));
+        let field = match native_field(dev, field_def_no, native_type) {
+            Some(f) => f,
+            None => continue
+        };
+
+        match existing {
+            Some(i) => message.fields[i] = field,
+            None => message.fields.push(field)
+        }
+    }
+}
+
+/// Native field with field definition number `field_def_no`,
+/// from developer field `dev`. Raw values are converted to the scale
+/// and offset of the native field, via the scale and offset of `dev`.
+/// The base type is that of the native field if listed in
+/// the "Field Type" column in Profile.xlsx, otherwise that of `dev`.
+/// Returns `None` for non-numerical values, and for values
+/// that the base type can not represent.
+///
+/// Developer data index and application ID are kept
+/// as provenance, see `DataField::application_id()`.
+fn native_field(dev: &DataField, field_def_no: u8, native_type: Option<&FitFieldType>) -> Option<DataField> {
+    let (scale, offset) = match native_type {
+        Some(ft) => (ft.scale, ft.offset),
+        None => (dev.scale(), dev.offset())
+    };
+
+    // Not `values_f64()`, which also converts units, e.g. semicircles to degrees
+    let values = (0 .. dev.data.len())
+        .map(|i| dev.data.as_f64_at(i)
+            .map(|raw| physical::to_physical(raw, dev.scale(), dev.offset(), None))
+            .map(|n| (n + offset.unwrap_or(0.)) * scale.unwrap_or(1.))
+        )
+        .collect::<Vec<_>>();
+
+    let base_type = native_type
+        .and_then(|ft| ft.base_type)
+        .unwrap_or(dev.definition.base_type.number());
+    let data = native_value(&values, base_type)?;
+
+    let mut attributes = match native_type {
+        Some(ft) => DataFieldAttributes::profile(ft.name, ft.scale, ft.offset, ft.units, None),
+        None => dev.attributes.to_owned().unwrap_or_default()
+    };
+    attributes.developer_data_index = dev.developer_data_index();
+    attributes.application_id = dev.application_id();
+
+    Some(DataField::native(field_def_no, BaseTypeDefinition::new(data.base_type()), data, attributes))
+}
+
+/// Raw values as FIT base type number `base_type`, with invalid values
+/// set to the invalid sentinel for the base type, e.g. `0` for `uint8z`.
+/// Integers are rounded, floats are kept as is.
+/// Returns `None` for non-numerical base types, and if any value
+/// is out of range for the base type or equals its invalid sentinel.
+fn native_value(values: &[Option<f64>], base_type: u8) -> Option<Value> {
+    let value = match base_type {
+        0 => Value::Enum(integers(values, u8::MAX)?),
+        1 => Value::Sint8(integers(values, i8::MAX)?),
+        2 => Value::Uint8(integers(values, u8::MAX)?),
+        3 => Value::Sint16(integers(values, i16::MAX)?),
+        4 => Value::Uint16(integers(values, u16::MAX)?),
+        5 => Value::Sint32(integers(values, i32::MAX)?),
+        6 => Value::Uint32(integers(values, u32::MAX)?),
+        8 => Value::Float32(values.iter().map(|v| v.map_or(f32::from_bits(u32::MAX), |n| n as f32)).collect()),
+        9 => Value::Float64(values.iter().map(|v| v.unwrap_or(f64::from_bits(u64::MAX))).collect()),
+        10 => Value::Uint8z(integers(values, 0)?),
+        11 => Value::Uint16z(integers(values, 0)?),
+        12 => Value::Uint32z(integers(values, 0)?),
+        13 => Value::Byte(integers(values, u8::MAX)?),
+        14 => Value::Sint64(integers(values, i64::MAX)?),
+        15 => Value::Uint64(integers(values, u64::MAX)?),
+        16 => Value::Uint64z(integers(values, 0)?),
+        _ => return None
+    };
+    Some(value)
+}
+
+/// Rounds `values` to integers of type `T`, with `invalid`,
+/// the invalid sentinel, for `None`.
+/// Returns `None` if any value does not fit `T`, or equals `invalid`.
+fn integers<T: TryFrom<i128> + PartialEq + Copy>(values: &[Option<f64>], invalid: T) -> Option<Vec<T>> {
+    values.iter()
+        .map(|v| match v {
+            Some(n) if n.is_finite() => T::try_from(n.round() as i128).ok()
+                .filter(|n| *n != invalid),
+            Some(_) => None,
+            None => Some(invalid)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn native_value_integers() {
+        let value = native_value(&[Some(249.6), None], 4).unwrap();
+        assert!(matches!(value, Value::Uint16(v) if v == [250, u16::MAX]));
+
+        let value = native_value(&[Some(-3.), None], 1).unwrap();
+        assert!(matches!(value, Value::Sint8(v) if v == [-3, i8::MAX]));
+    }
+
+    #[test]
+    fn native_value_z_types() {
+        // Invalid values are 0 for z types
+        let value = native_value(&[Some(255.), None], 10).unwrap();
+        assert!(matches!(value, Value::Uint8z(v) if v == [255, 0]));
+
+        // 0 would read as invalid
+        assert!(native_value(&[Some(0.)], 11).is_none());
+    }
+
+    #[test]
+    fn native_value_floats() {
+        let value = native_value(&[Some(1.25), None], 8).unwrap();
+        assert!(matches!(&value, Value::Float32(v) if v[0] == 1.25));
+        assert!(value.is_invalid_at(1));
+
+        let value = native_value(&[Some(-0.5)], 9).unwrap();
+        assert!(matches!(value, Value::Float64(v) if v == [-0.5]));
+    }
+
+    #[test]
+    fn native_value_out_of_range() {
+        assert!(native_value(&[Some(256.)], 2).is_none());
+        assert!(native_value(&[Some(-1.)], 6).is_none());
+        assert!(native_value(&[Some(f64::INFINITY)], 5).is_none());
+        // Invalid sentinel
+        assert!(native_value(&[Some(255.)], 0).is_none());
+        // Strings
+        assert!(native_value(&[Some(1.)], 7).is_none());
+    }
+}
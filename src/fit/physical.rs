@@ -0,0 +1,63 @@
+//! Physical values, i.e. raw FIT values with scale and offset applied,
+//! as described in the [FIT SDK](https://developer.garmin.com/fit/).
+
+use super::UnitSystem;
+
+/// Multiply by this to convert semicircles to decimal degrees.
+pub(crate) const SEMICIRCLES_TO_DEGREES: f64 = 180.0 / 2_147_483_648.0;
+
+/// Scaled physical values for a data field, together with their units.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PhysicalValue<'a> {
+    /// One value per element, `None` for invalid values.
+    pub values: Vec<Option<f64>>,
+    /// Units for the converted values,
+    /// e.g. `degrees` for fields logged as `semicircles`.
+    pub units: Option<&'a str>,
+    /// Field name, if set, e.g. `distance`.
+    /// Tells distances from other lengths in `PhysicalValue::to_system()`.
+    pub name: Option<&'a str>,
+}
+
+impl PhysicalValue<'_> {
+    /// Returns the first value, or `None` if invalid.
+    pub fn value(&self) -> Option<f64> {
+        self.values.first().copied().flatten()
+    }
+
+    /// Converts values and units to `system`,
+    /// e.g. `m/s` to `mph` for `UnitSystem::Imperial`.
+    /// See `UnitSystem::convert_field()`.
+    pub fn to_system(&self, system: UnitSystem) -> Self {
+        let units = match self.units {
+            Some(u) => u,
+            None => return self.to_owned()
+        };
+        let values = self.values.iter()
+            .map(|v| v.map(|n| system.convert_field(self.name, n, units).0))
+            .collect();
+        Self {
+            values,
+            units: Some(system.convert_field(self.name, 0., units).1),
+            name: self.name,
+        }
+    }
+}
+
+/// Applies scale and offset to `raw`, i.e. `raw / scale - offset`.
+/// Values in `semicircles` are converted to decimal degrees.
+pub(crate) fn to_physical(raw: f64, scale: Option<f64>, offset: Option<f64>, units: Option<&str>) -> f64 {
+    let value = raw / scale.unwrap_or(1.) - offset.unwrap_or(0.);
+    match units {
+        Some("semicircles") => value * SEMICIRCLES_TO_DEGREES,
+        _ => value
+    }
+}
+
+/// Returns units after `to_physical()`, i.e. `degrees` for `semicircles`.
+pub(crate) fn physical_units(units: Option<&str>) -> Option<&str> {
+    match units {
+        Some("semicircles") => Some("degrees"),
+        u => u
+    }
+}
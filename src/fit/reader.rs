@@ -0,0 +1,649 @@
+//! Streaming FIT reader.
+//!
+//! Reads data messages one at a time from any `std::io::Read` source,
+//! e.g. a file, stdin, a socket, or an in-memory byte slice.
+//! Only the definition table and developer field descriptions are kept in memory.
+//!
+//! ```rs
+//! use fit_rs::FitReader;
+//! use std::path::Path;
+//!
+//! fn main() -> Result<(), fit_rs::FitError> {
+//!     let reader = FitReader::open(Path::new("FITFILE.fit"))?;
+//!     for message in reader {
+//!         println!("{}", message?);
+//!     }
+//!     Ok(())
+//! }
+//! ```
+
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{BufReader, Cursor, ErrorKind, Read, Seek},
+    path::Path
+};
+
+use binrw::io::NoSeek;
+
+use crate::{errors::FitError, types::{DeveloperDataId, FieldDescriptionMessage}};
+
+use super::{
+    component::ComponentExpander,
+    crc::CrcReader,
+    message::MessageType,
+    BaseTypeDefinition,
+    CrcStatus,
+    Diagnostic,
+    DiagnosticKind,
+    DataMessage,
+    DefinitionMessage,
+    FitCrc,
+    FitFilter,
+    FitHeader,
+    FitSegment,
+    Message,
+    MessageHeader,
+};
+
+/// Streaming FIT reader. Iterates over data messages
+/// in logging/chronological order.
+///
+/// Chained FIT files are read in full, with each one listed
+/// in `FitReader::segments()` as it is encountered.
+/// Header and file CRCs are verified while reading.
+pub struct FitReader<R: Read> {
+    reader: NoSeek<CrcReader<BufReader<R>>>,
+    /// Header for current FIT file.
+    /// `None` before the first, and between chained FIT files.
+    header: Option<FitHeader>,
+    /// Total size in bytes, if known.
+    /// Used for deriving data size for corrupt or truncated FIT-files.
+    len: Option<u64>,
+    /// Data messages to return.
+    filter: FitFilter,
+    /// Expands component fields, if set.
+    expander: Option<ComponentExpander>,
+    debug: bool,
+    /// Record errors as diagnostics and resume parsing.
+    lenient: bool,
+    diagnostics: Vec<Diagnostic>,
+    /// Definition for each local ID.
+    definitions: HashMap<u8, DefinitionMessage>,
+    /// Keep all definitions in `definition_stream`, if set.
+    keep_definitions: bool,
+    /// All definitions in the order encountered,
+    /// if `keep_definitions` is set.
+    definition_stream: Vec<DefinitionMessage>,
+    /// Number of definitions read, for `DefinitionMessage.index`.
+    definition_count: usize,
+    field_descriptions: HashMap<(u8, u8), FieldDescriptionMessage>,
+    /// Developer data IDs by developer data index.
+    developer_data_ids: HashMap<u8, DeveloperDataId>,
+    /// Last full timestamp, for resolving compressed timestamp headers.
+    last_timestamp: Option<u32>,
+    segments: Vec<FitSegment>,
+    /// Data message index.
+    index: usize,
+    done: bool,
+}
+
+impl FitReader<File> {
+    /// Open FIT-file for streaming.
+    pub fn open(path: &Path) -> Result<Self, FitError> {
+        Ok(Self::new(File::open(path)?))
+    }
+}
+
+impl<R: Read> FitReader<R> {
+    /// New streaming FIT reader. Nothing is read
+    /// until the first data message is requested.
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader: NoSeek::new(CrcReader::new(BufReader::new(reader))),
+            header: None,
+            len: None,
+            filter: FitFilter::All,
+            expander: Some(ComponentExpander::default()),
+            debug: false,
+            lenient: false,
+            diagnostics: Vec::new(),
+            definitions: HashMap::new(),
+            keep_definitions: false,
+            definition_stream: Vec::new(),
+            definition_count: 0,
+            field_descriptions: HashMap::new(),
+            developer_data_ids: HashMap::new(),
+            last_timestamp: None,
+            segments: Vec::new(),
+            index: 0,
+            done: false,
+        }
+    }
+
+    /// Only return data messages, or data fields, that pass `filter`.
+    /// Field descriptions for developer data are processed
+    /// regardless of `filter`.
+    pub fn with_filter(self, filter: impl Into<FitFilter>) -> Self {
+        Self {
+            filter: filter.into(),
+            ..self
+        }
+    }
+
+    /// Expand component fields into derived fields,
+    /// e.g. `speed` into `enhanced_speed` for `record/20`,
+    /// see `FitComponent`. Enabled by default.
+    /// Fields already logged are not overwritten,
+    /// unless these only contain invalid values.
+    pub fn with_expansion(self, expand: bool) -> Self {
+        Self {
+            expander: expand.then(ComponentExpander::default),
+            ..self
+        }
+    }
+
+    /// Keep all definition messages in the order encountered,
+    /// for e.g. re-encoding with `FitWriter::with_definitions()`,
+    /// see `FitReader::definitions()`. Disabled by default,
+    /// since a FIT-file may redefine local IDs any number of times.
+    pub fn with_definitions(self, keep: bool) -> Self {
+        Self {
+            keep_definitions: keep,
+            ..self
+        }
+    }
+
+    /// Set total size in bytes, for deriving data size
+    /// for FIT-files that report a data size of 0,
+    /// or one that exceeds the file size.
+    pub(crate) fn with_len(self, len: u64) -> Self {
+        Self {
+            len: Some(len),
+            ..self
+        }
+    }
+
+    /// Print headers, definitions and data messages
+    /// while reading.
+    pub(crate) fn with_debug(self, debug: bool) -> Self {
+        Self {
+            debug,
+            ..self
+        }
+    }
+
+    /// Lenient mode. Instead of returning an error, each problem
+    /// is recorded as a `Diagnostic`, after which parsing resumes:
+    /// - Unknown definitions and invalid messages are skipped,
+    ///   and parsing resumes at the next plausible message header.
+    /// - Truncated data stops parsing without an error.
+    ///
+    /// Use `FitReader::diagnostics()` to list problems encountered.
+    pub fn with_lenient(self, lenient: bool) -> Self {
+        Self {
+            lenient,
+            ..self
+        }
+    }
+
+    /// Returns problems encountered so far in lenient mode.
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        &self.diagnostics
+    }
+
+    /// Consumes reader and returns problems encountered in lenient mode.
+    pub fn into_diagnostics(self) -> Vec<Diagnostic> {
+        self.diagnostics
+    }
+
+    /// Returns header for the FIT file currently being read.
+    pub fn header(&self) -> Option<&FitHeader> {
+        self.header.as_ref()
+    }
+
+    /// Returns the FIT files read so far, with header, CRC status,
+    /// and data message index range for each.
+    /// The file CRC is only set once the end of each FIT file has been reached.
+    pub fn segments(&self) -> &[FitSegment] {
+        &self.segments
+    }
+
+    /// Returns all definition messages read so far,
+    /// in the order encountered, see `DataMessage.definition`.
+    /// Unlike the definition table used for parsing,
+    /// these are kept for all chained FIT files.
+    /// Empty unless enabled via `FitReader::with_definitions()`.
+    pub fn definitions(&self) -> &[DefinitionMessage] {
+        &self.definition_stream
+    }
+
+    /// Consumes reader and returns the FIT files read so far.
+    pub fn into_segments(self) -> Vec<FitSegment> {
+        self.segments
+    }
+
+    /// Current byte offset.
+    fn position(&mut self) -> Result<u64, FitError> {
+        Ok(self.reader.stream_position()?)
+    }
+
+    fn is_eof(&mut self) -> Result<bool, FitError> {
+        Ok(self.reader.get_mut().is_eof()?)
+    }
+
+    /// Byte offset for the end of the data section in the current FIT file.
+    fn data_end(&self) -> u64 {
+        match self.segments.last() {
+            Some(segment) => {
+                let header = &segment.header;
+                let data_size = match self.len {
+                    Some(len) => header.data_size(len.saturating_sub(segment.offset) as usize) as u64,
+                    // Unknown data size, read until end of stream
+                    None if header.datasize == 0 => u64::MAX,
+                    None => header.datasize as u64,
+                };
+                (segment.offset + header.headersize as u64).saturating_add(data_size)
+            },
+            None => 0
+        }
+    }
+
+    /// Reads the header for the next FIT file.
+    /// Returns `false` if there is none, i.e. at end of stream,
+    /// or if whatever follows a chained FIT file is not a FIT header.
+    fn next_segment(&mut self) -> Result<bool, FitError> {
+        if self.is_eof()? {
+            return Ok(false)
+        }
+
+        let first = self.segments.is_empty();
+        let offset = self.position()?;
+
+        self.reader.get_mut().reset();
+
+        // Read header in full first, for header CRC
+        // and to skip any bytes beyond the 14 byte header.
+        let mut bytes = vec![0_u8; 1];
+        self.reader.read_exact(&mut bytes)?;
+        let size = bytes[0] as usize;
+        if size < 12 {
+            return match first {
+                true => Err(FitError::UnexpectedHeaderSize(size)),
+                false => Ok(false)
+            }
+        }
+        bytes.resize(size, 0);
+        match self.reader.read_exact(&mut bytes[1..]) {
+            Ok(()) => (),
+            Err(err) if !first && err.kind() == ErrorKind::UnexpectedEof => return Ok(false),
+            Err(err) => return Err(err.into())
+        }
+
+        if !first && !FitHeader::is_header(&bytes) {
+            return Ok(false)
+        }
+
+        let header = FitHeader::new(&mut Cursor::new(&bytes))?;
+        let crc = FitCrc {
+            header: header.header_crc(&bytes),
+            file: CrcStatus::Absent,
+        };
+
+        if self.debug {println!("OFFSET {offset} | {header:#?}\nCRC header: {}", crc.header)}
+
+        // Definitions, developer data, and timestamps
+        // do not carry over between chained FIT files.
+        self.definitions.clear();
+        self.field_descriptions.clear();
+        self.developer_data_ids.clear();
+        self.last_timestamp = None;
+        if let Some(expander) = self.expander.as_mut() {
+            expander.reset();
+        }
+
+        self.segments.push(FitSegment {
+            offset,
+            header,
+            crc,
+            range: self.index .. self.index,
+        });
+        self.header = Some(header);
+
+        Ok(true)
+    }
+
+    /// Reads and verifies the trailing file CRC for the current FIT file.
+    fn end_segment(&mut self) -> Result<(), FitError> {
+        let computed = self.reader.get_ref().crc();
+        let position = self.position()?;
+
+        let mut bytes = [0_u8; 2];
+        let stored = match self.reader.read_exact(&mut bytes) {
+            Ok(()) => Some(u16::from_le_bytes(bytes)),
+            Err(err) if err.kind() == ErrorKind::UnexpectedEof => None,
+            Err(err) => return Err(err.into())
+        };
+
+        if let Some(segment) = self.segments.last_mut() {
+            let header = &segment.header;
+            // No CRC for FIT-files that report a data size of 0,
+            // or that are truncated.
+            let complete = header.datasize != 0
+                && position == segment.offset + header.headersize as u64 + header.datasize as u64;
+            if complete {
+                segment.crc.file = CrcStatus::compare(stored, computed);
+            }
+            if self.debug {println!("OFFSET {position} | CRC file: {}", segment.crc.file)}
+        }
+
+        self.header = None;
+
+        Ok(())
+    }
+
+    /// Reads messages until the next data message.
+    /// Returns `None` at end of stream.
+    fn next_message(&mut self) -> Result<Option<DataMessage>, FitError> {
+        loop {
+            if self.header.is_none() {
+                match self.next_segment() {
+                    Ok(true) => (),
+                    Ok(false) => return Ok(None),
+                    Err(err) if self.lenient => {
+                        let offset = self.position()?;
+                        self.diagnose(offset, None, err, 0);
+                        return Ok(None)
+                    },
+                    Err(err) => return Err(err)
+                }
+            }
+
+            let position = self.position()?;
+
+            if position >= self.data_end() || self.is_eof()? {
+                self.end_segment()?;
+                continue;
+            }
+
+            if self.debug {print!("OFFSET {position} | ")}
+
+            // Local ID for diagnostics
+            let local = match self.lenient {
+                true => self.reader.get_mut().peek()?
+                    .first()
+                    .map(|header| MessageHeader::new(*header).id()),
+                false => None
+            };
+
+            let message = match Message::parse(&mut self.reader, &self.definitions, &mut self.last_timestamp) {
+                Ok(msg) => msg,
+                // Failing at end of stream means the message was cut short.
+                // Checked explicitly, since the EOF error may be wrapped
+                // in a failed rewind for non-seekable streams.
+                Err(_) if self.is_eof()? => {
+                    let err = FitError::Truncated{offset: position};
+                    match self.lenient {
+                        true => {
+                            self.diagnose(position, local, err, 0);
+                            continue;
+                        },
+                        false => return Err(err)
+                    }
+                },
+                Err(err) if self.lenient => {
+                    self.recover(position, local, err)?;
+                    continue;
+                },
+                Err(err) => return Err(err)
+            };
+            let id = message.id();
+
+            match message.message_type() {
+
+                // Definition message
+                MessageType::Definition(mut definition) => {
+
+                    // Add field descriptions for developer data
+                    definition.with_field_descriptions(&self.field_descriptions);
+                    definition.with_developer_data_ids(&self.developer_data_ids);
+
+                    definition.local = id;
+                    definition.index = self.definition_count;
+                    self.definition_count += 1;
+
+                    if self.debug {println!("{definition:#?}")}
+
+                    if self.keep_definitions {
+                        self.definition_stream.push(definition.clone());
+                    }
+                    self.definitions.insert(
+                        id,
+                        definition
+                    );
+                },
+
+                // Data message
+                MessageType::Data(mut data) => {
+
+                    // Parse and store custom developer definitions,
+                    // regardless of filter
+                    if data.global == 206 {
+                        match FieldDescriptionMessage::new(&data) {
+                            Ok(field_descr) => {
+                                // Require both field_definition_number and developer_data_index
+                                // to create a unique key since third parties are not
+                                // always using this correctly, sometimes causing ID collisions
+                                self.field_descriptions.insert(
+                                    (field_descr.field_definition_number, field_descr.developer_data_index),
+                                    field_descr,
+                                );
+                            },
+                            Err(err) if self.lenient => self.diagnose(position, Some(id), err, 0),
+                            Err(err) => return Err(err)
+                        }
+                    }
+
+                    // Parse and store developer data IDs,
+                    // regardless of filter
+                    if data.global == 207 {
+                        match DeveloperDataId::new(&data) {
+                            Ok(developer_data_id) => {
+                                self.developer_data_ids.insert(
+                                    developer_data_id.developer_data_index,
+                                    developer_data_id,
+                                );
+                            },
+                            Err(err) if self.lenient => self.diagnose(position, Some(id), err, 0),
+                            Err(err) => return Err(err)
+                        }
+                    }
+
+                    // Expand components before filtering,
+                    // since accumulated values depend on all messages
+                    if let Some(expander) = self.expander.as_mut() {
+                        expander.expand(&mut data);
+                    }
+
+                    // Ignore message if not kept by filter
+                    let mut data = match self.filter.apply(data) {
+                        Some(d) => d,
+                        None => continue
+                    };
+
+                    // Set index to preserve chronological order if filtering etc
+                    data.index = self.index;
+
+                    if self.debug {println!("{data:#?}")}
+
+                    self.index += 1; // data message index
+
+                    if let Some(segment) = self.segments.last_mut() {
+                        segment.range.end = self.index;
+                    }
+
+                    return Ok(Some(data))
+                },
+
+                _ => ()
+            }
+        }
+    }
+}
+
+impl<R: Read> FitReader<R> {
+    /// Records a diagnostic for `err`.
+    fn diagnose(&mut self, offset: u64, local: Option<u8>, err: FitError, skipped: u64) {
+        let kind = match &err {
+            FitError::UnknownDefinition{..} => DiagnosticKind::UnknownDefinition,
+            FitError::ErrorAssigningField{global: 206, ..}
+            | FitError::UnexpectedMessageType{expected: 206, ..} => DiagnosticKind::InvalidFieldDescription,
+            e if e.is_eof() => DiagnosticKind::Truncated,
+            _ => DiagnosticKind::InvalidMessage,
+        };
+
+        let diagnostic = Diagnostic {offset, local, kind, error: err, skipped};
+
+        if self.debug {println!("{diagnostic}")}
+
+        self.diagnostics.push(diagnostic);
+    }
+
+    /// Lenient mode only. Records a diagnostic for the message at `offset`
+    /// that failed to parse, then skips ahead to the next plausible message header.
+    fn recover(&mut self, offset: u64, local: Option<u8>, err: FitError) -> Result<(), FitError> {
+        let skipped = self.resync()?;
+        self.diagnose(offset, local, err, skipped);
+        Ok(())
+    }
+
+    /// Skips bytes until a plausible message header is found,
+    /// or the end of the data section is reached.
+    /// Returns number of bytes skipped.
+    fn resync(&mut self) -> Result<u64, FitError> {
+        let data_end = self.data_end();
+        let mut skipped = 0;
+        let mut byte = [0_u8; 1];
+
+        loop {
+            let position = self.position()?;
+            if position >= data_end {
+                break
+            }
+            // Data section only, so that the file CRC is not read as a message
+            let bytes = self.reader.get_mut().peek()?;
+            let bytes = &bytes[.. bytes.len().min(usize::try_from(data_end - position).unwrap_or(usize::MAX))];
+            if bytes.is_empty() || is_plausible_message(bytes, &self.definitions) {
+                break
+            }
+            self.reader.read_exact(&mut byte)?;
+            skipped += 1;
+        }
+
+        Ok(skipped)
+    }
+}
+
+/// Returns size in bytes, including the header, if `bytes`
+/// starts with a plausible definition message.
+fn plausible_definition(bytes: &[u8]) -> Option<usize> {
+    let header = *bytes.first()?;
+    // Normal header, definition message, reserved bit 4 not set
+    if header & 0b1101_0000 != 0b0100_0000 {
+        return None
+    }
+    // Reserved byte must be 0, architecture 0 or 1
+    if *bytes.get(1)? != 0 || *bytes.get(2)? > 1 {
+        return None
+    }
+    let field_count = *bytes.get(5)? as usize;
+    let mut size = 6 + 3 * field_count;
+    let fields = bytes.get(6 .. size)?;
+    let fields_ok = fields.chunks_exact(3)
+        .all(|f| {
+            let base_type = BaseTypeDefinition::new(f[2]);
+            base_type.reserved() == 0
+                && base_type.base_len().is_ok_and(|len| f[1] != 0 && f[1] % len == 0)
+        });
+    if !fields_ok {
+        return None
+    }
+    // Developer fields
+    if MessageHeader::new(header).dev() {
+        let dev_field_count = *bytes.get(size)? as usize;
+        size += 1 + 3 * dev_field_count;
+    }
+    Some(size)
+}
+
+/// Returns size in bytes, including the header, if `bytes`
+/// starts with a plausible data message, i.e. a header
+/// with a local ID that has a definition.
+fn plausible_data(bytes: &[u8], definitions: &HashMap<u8, DefinitionMessage>) -> Option<usize> {
+    let header = MessageHeader::new(*bytes.first()?);
+    // Reserved bits must not be set for normal data headers
+    if header.comp_time().is_none() && bytes[0] & 0b0111_0000 != 0 {
+        return None
+    }
+    definitions.get(&header.id())
+        .map(|def| 1 + def.data_size() as usize)
+}
+
+/// Number of consecutive plausible messages required
+/// to resume parsing at a data message.
+const RESYNC_CHAIN: usize = 3;
+
+/// Returns `true` if `bytes` starts with a plausible message header.
+///
+/// Definition messages have enough structure to be checked on their own.
+/// A data message must have a normal header, and be followed by
+/// further plausible messages, since almost any byte could pass
+/// as a data message header otherwise.
+/// Messages extending beyond `bytes` can not be checked and are accepted.
+fn is_plausible_message(bytes: &[u8], definitions: &HashMap<u8, DefinitionMessage>) -> bool {
+    if plausible_definition(bytes).is_some() {
+        return true
+    }
+    // Do not resume at compressed timestamp headers,
+    // any byte above 0x7F would qualify
+    if bytes.first().is_none_or(|b| MessageHeader::new(*b).comp_time().is_some()) {
+        return false
+    }
+
+    let mut rest = bytes;
+    for _ in 0 .. RESYNC_CHAIN {
+        let size = match plausible_definition(rest).or_else(|| plausible_data(rest, definitions)) {
+            Some(size) => size,
+            None => return false
+        };
+        match rest.get(size ..) {
+            Some(next) if !next.is_empty() => rest = next,
+            _ => return true
+        }
+    }
+
+    true
+}
+
+impl<R: Read> Iterator for FitReader<R> {
+    type Item = Result<DataMessage, FitError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None
+        }
+        match self.next_message() {
+            Ok(Some(data)) => Some(Ok(data)),
+            Ok(None) => {
+                self.done = true;
+                None
+            },
+            // Stop on first error, since the stream
+            // can not be realigned with message boundaries.
+            Err(err) => {
+                self.done = true;
+                Some(Err(err))
+            }
+        }
+    }
+}
@@ -0,0 +1,40 @@
+//! Chained FIT files.
+//!
+//! The FIT protocol allows for several complete FIT files
+//! to be concatenated into one, each with its own header,
+//! data section, and CRC. Each of these is represented as a `FitSegment`.
+
+use std::ops::Range;
+
+use super::{FitCrc, FitHeader};
+
+/// A single FIT file within a (possibly chained) FIT file.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FitSegment {
+    /// Byte offset for the start of the segment header.
+    pub offset: u64,
+    /// Segment header.
+    pub header: FitHeader,
+    /// Segment header and file CRC status.
+    pub crc: FitCrc,
+    /// Slice range for the segment's data messages in `Fit.records`.
+    pub range: Range<usize>,
+}
+
+impl FitSegment {
+    /// Returns slice range for the segment's data messages in `Fit.records`.
+    pub fn range(&self) -> Range<usize> {
+        self.range.to_owned()
+    }
+
+    /// Returns number of data messages in segment.
+    pub fn len(&self) -> usize {
+        self.range.len()
+    }
+
+    /// Returns `true` if segment contains no data messages.
+    pub fn is_empty(&self) -> bool {
+        self.range.is_empty()
+    }
+}
@@ -0,0 +1,33 @@
+//! Absolute vs relative FIT timestamps.
+//!
+//! The FIT SDK specifies that `date_time` values below `0x10000000`
+//! are relative system time, e.g. seconds since the device was powered on,
+//! and that values above are seconds since 1989-12-31T00:00:00 UTC.
+//! Garmin VIRB cameras log relative timestamps, that are shifted
+//! to absolute ones via `timestamp_correlation/162`,
+//! while watches and bike computers log absolute timestamps.
+
+use crate::constants::FIT_MIN_ABSOLUTE_TIMESTAMP;
+
+/// Timestamp mode for a FIT `date_time` value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TimestampMode {
+    /// Seconds since 1989-12-31T00:00:00 UTC,
+    /// e.g. watches and bike computers.
+    Absolute,
+    /// Seconds since device power-on or similar,
+    /// e.g. Garmin VIRB. Requires `timestamp_correlation/162`
+    /// to be converted to absolute time.
+    Relative,
+}
+
+impl TimestampMode {
+    /// Returns timestamp mode for a FIT `date_time` value.
+    pub fn from_timestamp(timestamp: u32) -> Self {
+        match timestamp < FIT_MIN_ABSOLUTE_TIMESTAMP {
+            true => Self::Relative,
+            false => Self::Absolute
+        }
+    }
+}
@@ -0,0 +1,96 @@
+//! Unit conversion for physical values, i.e. values with scale and offset applied.
+//! Conversions are keyed on the units in Profile.xlsx, e.g. `m/s` or `C`,
+//! as listed in `FitFieldType.units` and `DataFieldAttributes.units`,
+//! after `semicircles` have been converted to `degrees`, see `PhysicalValue.units`.
+
+/// Meters per foot.
+const METERS_PER_FOOT: f64 = 0.3048;
+/// Meters per mile.
+const METERS_PER_MILE: f64 = 1609.344;
+/// Fields in `m` that are distances covered,
+/// as opposed to altitudes or other lengths.
+const DISTANCE_FIELDS: [&str; 5] = [
+    "distance",
+    "total_distance",
+    "current_dist",
+    "odometer",
+    "distance_duration_alert",
+];
+
+/// Preferred unit system for presenting physical values.
+/// Units with no conversion for the chosen system,
+/// e.g. `bpm` or `watts`, are left as is.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum UnitSystem {
+    /// Units as specified in Profile.xlsx,
+    /// except `semicircles`, which are converted to decimal degrees.
+    #[default]
+    Profile,
+    /// Speed in km/h, pace in min/km.
+    Metric,
+    /// Speed in mph, distance in miles, altitude and other lengths in feet,
+    /// temperature in Fahrenheit, pace in min/mi.
+    Imperial,
+}
+
+impl UnitSystem {
+    /// Converts `value` in `units` to this unit system,
+    /// and returns the converted value together with its units.
+    ///
+    /// `value` and `units` must be physical, i.e. with scale and offset applied
+    /// and `semicircles` converted to `degrees`, as in `PhysicalValue`
+    /// from `DataField::physical()`. Pass `PhysicalValue.units`,
+    /// not the units in the FIT profile.
+    ///
+    /// `m` is converted to feet for `UnitSystem::Imperial`, which suits altitude.
+    /// Use `UnitSystem::convert_field()` to convert distances to miles.
+    pub fn convert<'a>(&self, value: f64, units: &'a str) -> (f64, &'a str) {
+        match (self, units) {
+            (UnitSystem::Metric, "m/s") => (value * 3.6, "km/h"),
+            (UnitSystem::Imperial, "m/s") => (value * 3600. / METERS_PER_MILE, "mph"),
+            (UnitSystem::Imperial, "m") => (value / METERS_PER_FOOT, "ft"),
+            (UnitSystem::Imperial, "C") => (value * 1.8 + 32., "F"),
+            (_, u) => (value, u)
+        }
+    }
+
+    /// Converts `value` in `units` for the field named `name`,
+    /// see `UnitSystem::convert()`. Distances, such as `distance`
+    /// and `total_distance`, are converted to miles rather than feet
+    /// for `UnitSystem::Imperial`.
+    pub fn convert_field<'a>(&self, name: Option<&str>, value: f64, units: &'a str) -> (f64, &'a str) {
+        match (self, units, name) {
+            (UnitSystem::Imperial, "m", Some(n)) if DISTANCE_FIELDS.contains(&n) => (value / METERS_PER_MILE, "mi"),
+            _ => self.convert(value, units)
+        }
+    }
+
+    /// Converts `speed` in m/s to pace in minutes per kilometer,
+    /// or minutes per mile for `UnitSystem::Imperial`.
+    /// Returns `None` if `speed` is zero or negative,
+    /// e.g. when stationary.
+    pub fn pace(&self, speed: f64) -> Option<f64> {
+        if speed <= 0. {
+            return None
+        }
+        let meters = match self {
+            UnitSystem::Imperial => METERS_PER_MILE,
+            _ => 1000.
+        };
+        Some(meters / speed / 60.)
+    }
+
+    /// Units for `UnitSystem::pace()`.
+    pub fn pace_units(&self) -> &'static str {
+        match self {
+            UnitSystem::Imperial => "min/mi",
+            _ => "min/km"
+        }
+    }
+}
+
+/// Rounds `value` to at most three decimals for presentation.
+pub(crate) fn round(value: f64) -> f64 {
+    (value * 1000.).round() / 1000.
+}
@@ -1,6 +1,6 @@
 //! FIT core data types, such as string and numerical values.
 
-use std::io::{Read, BufRead, Seek};
+use std::io::{Read, Seek};
 
 use binrw::{BinRead, BinReaderExt};
 
@@ -10,8 +10,17 @@ use super::DefinitionField;
 
 /// FIT data types.
 /// See FIT SDK for specifics.
+///
+/// With the `serde` feature, values serialize as an object with
+/// the FIT base type name as `type`, and the values as `values`,
+/// e.g. `{"type": "uint16", "values": [1, 65535]}`,
+/// or `{"type": "string", "values": "VIRB"}` for strings.
+/// Invalid `float32` and `float64` values serialize as `null`,
+/// since JSON has no NaN.
 // #[derive(Debug, Clone, BinRead)]
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", content = "values", rename_all = "lowercase"))]
 pub enum Value {
     /// Enum is single-byte value according to FIT SDK,
     /// but it still appears as an array (Vec<u8>,
@@ -43,9 +52,9 @@ pub enum Value {
     /// Base type: 7
     String(String),
     /// Base type: 8
-    Float32(Vec<f32>),
+    Float32(#[cfg_attr(feature = "serde", serde(with = "serde_float32"))] Vec<f32>),
     /// Base type: 9
-    Float64(Vec<f64>),
+    Float64(#[cfg_attr(feature = "serde", serde(with = "serde_float64"))] Vec<f64>),
     /// Base type: 10
     Uint8z(Vec<u8>),
     /// Base type: 11
@@ -62,6 +71,46 @@ pub enum Value {
     Uint64z(Vec<u64>),
 }
 
+/// Serializes invalid `float32` values, i.e. NaN, as `null`,
+/// and deserializes `null` as the invalid sentinel.
+#[cfg(feature = "serde")]
+mod serde_float32 {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub(super) fn serialize<S: Serializer>(values: &[f32], serializer: S) -> Result<S::Ok, S::Error> {
+        values.iter()
+            .map(|v| (!v.is_nan()).then_some(*v))
+            .collect::<Vec<_>>()
+            .serialize(serializer)
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<f32>, D::Error> {
+        Ok(Vec::<Option<f32>>::deserialize(deserializer)?.into_iter()
+            .map(|v| v.unwrap_or(f32::from_bits(u32::MAX)))
+            .collect())
+    }
+}
+
+/// Serializes invalid `float64` values, i.e. NaN, as `null`,
+/// and deserializes `null` as the invalid sentinel.
+#[cfg(feature = "serde")]
+mod serde_float64 {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub(super) fn serialize<S: Serializer>(values: &[f64], serializer: S) -> Result<S::Ok, S::Error> {
+        values.iter()
+            .map(|v| (!v.is_nan()).then_some(*v))
+            .collect::<Vec<_>>()
+            .serialize(serializer)
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<f64>, D::Error> {
+        Ok(Vec::<Option<f64>>::deserialize(deserializer)?.into_iter()
+            .map(|v| v.unwrap_or(f64::from_bits(u64::MAX)))
+            .collect())
+    }
+}
+
 impl Default for Value {
     fn default() -> Self {
         Self::Byte(Vec::default())
@@ -74,8 +123,15 @@ impl AsRef<Value> for Value {
     }
 }
 
+// Typed accessors return `None` for invalid values, i.e. missing data.
+// Single values only check the first value, `Vec`s are only `None`
+// if all values are invalid. See `Value::is_invalid_at()`.
+
 impl Into<Option<u8>> for &Value {
     fn into(self) -> Option<u8> {
+        if self.is_invalid_at(0) {
+            return None
+        }
         match self {
             Value::Uint8(n)
             | Value::Uint8z(n)
@@ -86,8 +142,26 @@ impl Into<Option<u8>> for &Value {
     }
 }
 
+impl Into<Option<Vec<u8>>> for &Value {
+    fn into(self) -> Option<Vec<u8>> {
+        if self.is_invalid() {
+            return None
+        }
+        match self {
+            Value::Uint8(n)
+            | Value::Uint8z(n)
+            | Value::Byte(n)
+            | Value::Enum(n) => Some(n.to_owned()),
+            _ => None,
+        }
+    }
+}
+
 impl Into<Option<i8>> for &Value {
     fn into(self) -> Option<i8> {
+        if self.is_invalid_at(0) {
+            return None
+        }
         match self {
             Value::Sint8(n) => n.first().cloned(),
             _ => None,
@@ -97,6 +171,9 @@ impl Into<Option<i8>> for &Value {
 
 impl Into<Option<i16>> for &Value {
     fn into(self) -> Option<i16> {
+        if self.is_invalid_at(0) {
+            return None
+        }
         match self {
             Value::Sint16(n) => n.first().cloned(),
             _ => None,
@@ -106,6 +183,9 @@ impl Into<Option<i16>> for &Value {
 
 impl Into<Option<Vec<i16>>> for &Value {
     fn into(self) -> Option<Vec<i16>> {
+        if self.is_invalid() {
+            return None
+        }
         match self {
             Value::Sint16(n) => Some(n.to_owned()),
             _ => None,
@@ -115,6 +195,9 @@ impl Into<Option<Vec<i16>>> for &Value {
 
 impl Into<Option<u16>> for &Value {
     fn into(self) -> Option<u16> {
+        if self.is_invalid_at(0) {
+            return None
+        }
         match self {
             Value::Uint16(n) 
             | Value::Uint16z(n) => n.first().cloned(),
@@ -125,6 +208,9 @@ impl Into<Option<u16>> for &Value {
 
 impl Into<Option<Vec<u16>>> for &Value {
     fn into(self) -> Option<Vec<u16>> {
+        if self.is_invalid() {
+            return None
+        }
         match self {
             Value::Uint16(n) 
             | Value::Uint16z(n) => Some(n.to_owned()),
@@ -135,6 +221,9 @@ impl Into<Option<Vec<u16>>> for &Value {
 
 impl Into<Option<i32>> for &Value {
     fn into(self) -> Option<i32> {
+        if self.is_invalid_at(0) {
+            return None
+        }
         match self {
             Value::Sint32(n) => n.first().cloned(),
             _ => None,
@@ -144,6 +233,9 @@ impl Into<Option<i32>> for &Value {
 
 impl Into<Option<Vec<i32>>> for &Value {
     fn into(self) -> Option<Vec<i32>> {
+        if self.is_invalid() {
+            return None
+        }
         match self {
             Value::Sint32(n) => Some(n.to_owned()),
             _ => None,
@@ -153,6 +245,9 @@ impl Into<Option<Vec<i32>>> for &Value {
 
 impl Into<Option<u32>> for &Value {
     fn into(self) -> Option<u32> {
+        if self.is_invalid_at(0) {
+            return None
+        }
         match self {
             Value::Uint16(n) 
             | Value::Uint16z(n) => n.first().cloned().map(u32::from),
@@ -165,6 +260,9 @@ impl Into<Option<u32>> for &Value {
 
 impl Into<Option<Vec<u32>>> for &Value {
     fn into(self) -> Option<Vec<u32>> {
+        if self.is_invalid() {
+            return None
+        }
         match self {
             Value::Uint16(n) 
             | Value::Uint16z(n) => Some(n.iter().map(|v| u32::from(*v)).collect()),
@@ -177,6 +275,9 @@ impl Into<Option<Vec<u32>>> for &Value {
 
 impl Into<Option<String>> for &Value {
     fn into(self) -> Option<String> {
+        if self.is_invalid() {
+            return None
+        }
         match self {
             Value::String(s) => Some(s.to_owned()),
             _ => None,
@@ -185,12 +286,178 @@ impl Into<Option<String>> for &Value {
 }
 
 impl Value {
-    /// Reads FIT values from `std::io::Cursor`
+    /// Returns number of values.
+    /// Strings count as a single value.
+    pub fn len(&self) -> usize {
+        match self {
+            Value::Enum(v) | Value::Uint8(v) | Value::Uint8z(v) | Value::Byte(v) => v.len(),
+            Value::Sint8(v) => v.len(),
+            Value::Sint16(v) => v.len(),
+            Value::Uint16(v) | Value::Uint16z(v) => v.len(),
+            Value::Sint32(v) => v.len(),
+            Value::Uint32(v) | Value::Uint32z(v) => v.len(),
+            Value::String(_) => 1,
+            Value::Float32(v) => v.len(),
+            Value::Float64(v) => v.len(),
+            Value::Sint64(v) => v.len(),
+            Value::Uint64(v) | Value::Uint64z(v) => v.len(),
+        }
+    }
+
+    /// Returns `true` if there are no values.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if the value at `index` equals the invalid
+    /// sentinel for its base type, as defined in the FIT SDK,
+    /// i.e. the value is missing. E.g. `0xFF` for `enum` and `uint8`,
+    /// `0x7FFF` for `sint16`, `0` for the `z` types,
+    /// and an empty string for `string`.
+    ///
+    /// Also returns `true` if `index` is out of bounds.
+    pub fn is_invalid_at(&self, index: usize) -> bool {
+        match self {
+            Value::Enum(v)
+            | Value::Uint8(v)
+            | Value::Byte(v) => v.get(index).is_none_or(|n| *n == u8::MAX),
+            Value::Sint8(v) => v.get(index).is_none_or(|n| *n == i8::MAX),
+            Value::Sint16(v) => v.get(index).is_none_or(|n| *n == i16::MAX),
+            Value::Uint16(v) => v.get(index).is_none_or(|n| *n == u16::MAX),
+            Value::Sint32(v) => v.get(index).is_none_or(|n| *n == i32::MAX),
+            Value::Uint32(v) => v.get(index).is_none_or(|n| *n == u32::MAX),
+            Value::String(s) => index != 0 || s.is_empty(),
+            Value::Float32(v) => v.get(index).is_none_or(|n| n.to_bits() == u32::MAX),
+            Value::Float64(v) => v.get(index).is_none_or(|n| n.to_bits() == u64::MAX),
+            Value::Uint8z(v) => v.get(index).is_none_or(|n| *n == 0),
+            Value::Uint16z(v) => v.get(index).is_none_or(|n| *n == 0),
+            Value::Uint32z(v) => v.get(index).is_none_or(|n| *n == 0),
+            Value::Sint64(v) => v.get(index).is_none_or(|n| *n == i64::MAX),
+            Value::Uint64(v) => v.get(index).is_none_or(|n| *n == u64::MAX),
+            Value::Uint64z(v) => v.get(index).is_none_or(|n| *n == 0),
+        }
+    }
+
+    /// Returns `true` if all values equal the invalid sentinel
+    /// for their base type, or if there are no values.
+    /// Arrays, such as `byte` arrays, are only invalid
+    /// if every value is invalid.
+    /// See `Value::is_invalid_at()` for checking single values.
+    pub fn is_invalid(&self) -> bool {
+        (0 .. self.len().max(1)).all(|i| self.is_invalid_at(i))
+    }
+
+    /// Returns the value at `index` as `f64` for numerical types.
+    /// Returns `None` for invalid values, strings,
+    /// and if `index` is out of bounds.
+    pub(crate) fn as_f64_at(&self, index: usize) -> Option<f64> {
+        if self.is_invalid_at(index) {
+            return None
+        }
+        match self {
+            Value::Enum(v) | Value::Uint8(v) | Value::Uint8z(v) | Value::Byte(v) => v.get(index).map(|n| *n as f64),
+            Value::Sint8(v) => v.get(index).map(|n| *n as f64),
+            Value::Sint16(v) => v.get(index).map(|n| *n as f64),
+            Value::Uint16(v) | Value::Uint16z(v) => v.get(index).map(|n| *n as f64),
+            Value::Sint32(v) => v.get(index).map(|n| *n as f64),
+            Value::Uint32(v) | Value::Uint32z(v) => v.get(index).map(|n| *n as f64),
+            Value::Float32(v) => v.get(index).map(|n| *n as f64),
+            Value::Float64(v) => v.get(index).copied(),
+            Value::Sint64(v) => v.get(index).map(|n| *n as f64),
+            Value::Uint64(v) | Value::Uint64z(v) => v.get(index).map(|n| *n as f64),
+            Value::String(_) => None,
+        }
+    }
+
+    /// Returns the first value as `u64` for integer types,
+    /// e.g. for matching subfield reference values.
+    /// Returns `None` for invalid values, negative values,
+    /// strings, and floats.
+    pub(crate) fn as_u64(&self) -> Option<u64> {
+        if self.is_invalid_at(0) {
+            return None
+        }
+        match self {
+            Value::Enum(v) | Value::Uint8(v) | Value::Uint8z(v) | Value::Byte(v) => v.first().map(|n| *n as u64),
+            Value::Sint8(v) => v.first().and_then(|n| u64::try_from(*n).ok()),
+            Value::Sint16(v) => v.first().and_then(|n| u64::try_from(*n).ok()),
+            Value::Uint16(v) | Value::Uint16z(v) => v.first().map(|n| *n as u64),
+            Value::Sint32(v) => v.first().and_then(|n| u64::try_from(*n).ok()),
+            Value::Uint32(v) | Value::Uint32z(v) => v.first().map(|n| *n as u64),
+            Value::Sint64(v) => v.first().and_then(|n| u64::try_from(*n).ok()),
+            Value::Uint64(v) | Value::Uint64z(v) => v.first().copied(),
+            Value::String(_) | Value::Float32(_) | Value::Float64(_) => None,
+        }
+    }
+
+    /// Returns values as little-endian bytes,
+    /// for extracting bit-packed components.
+    /// Returns `None` for strings and floats.
+    pub(crate) fn to_le_bytes(&self) -> Option<Vec<u8>> {
+        match self {
+            Value::Enum(v) | Value::Uint8(v) | Value::Uint8z(v) | Value::Byte(v) => Some(v.to_owned()),
+            Value::Sint8(v) => Some(v.iter().flat_map(|n| n.to_le_bytes()).collect()),
+            Value::Sint16(v) => Some(v.iter().flat_map(|n| n.to_le_bytes()).collect()),
+            Value::Uint16(v) | Value::Uint16z(v) => Some(v.iter().flat_map(|n| n.to_le_bytes()).collect()),
+            Value::Sint32(v) => Some(v.iter().flat_map(|n| n.to_le_bytes()).collect()),
+            Value::Uint32(v) | Value::Uint32z(v) => Some(v.iter().flat_map(|n| n.to_le_bytes()).collect()),
+            Value::Sint64(v) => Some(v.iter().flat_map(|n| n.to_le_bytes()).collect()),
+            Value::Uint64(v) | Value::Uint64z(v) => Some(v.iter().flat_map(|n| n.to_le_bytes()).collect()),
+            Value::String(_) | Value::Float32(_) | Value::Float64(_) => None,
+        }
+    }
+
+    /// Returns the FIT base type byte for the value,
+    /// including the endian ability bit for multi-byte types,
+    /// e.g. `0x84` for `uint16`.
+    pub(crate) fn base_type(&self) -> u8 {
+        match self {
+            Value::Enum(_) => 0x00,
+            Value::Sint8(_) => 0x01,
+            Value::Uint8(_) => 0x02,
+            Value::Sint16(_) => 0x83,
+            Value::Uint16(_) => 0x84,
+            Value::Sint32(_) => 0x85,
+            Value::Uint32(_) => 0x86,
+            Value::String(_) => 0x07,
+            Value::Float32(_) => 0x88,
+            Value::Float64(_) => 0x89,
+            Value::Uint8z(_) => 0x0A,
+            Value::Uint16z(_) => 0x8B,
+            Value::Uint32z(_) => 0x8C,
+            Value::Byte(_) => 0x0D,
+            Value::Sint64(_) => 0x8E,
+            Value::Uint64(_) => 0x8F,
+            Value::Uint64z(_) => 0x90,
+        }
+    }
+
+    /// Returns values as little-endian bytes, for encoding.
+    /// Strings are zero-padded to `size` bytes,
+    /// or null-terminated if these do not fit.
+    pub(crate) fn to_bytes(&self, size: usize) -> Vec<u8> {
+        match self {
+            Value::String(s) => {
+                let mut bytes = s.as_bytes().to_vec();
+                let len = match size >= bytes.len() {
+                    true => size,
+                    false => bytes.len() + 1
+                };
+                bytes.resize(len, 0);
+                bytes
+            },
+            Value::Float32(v) => v.iter().flat_map(|n| n.to_le_bytes()).collect(),
+            Value::Float64(v) => v.iter().flat_map(|n| n.to_le_bytes()).collect(),
+            _ => self.to_le_bytes().unwrap_or_default()
+        }
+    }
+
+    /// Reads FIT values from `reader`
     /// with endianess derived via `arch`
     /// (`0` = Little Endian, `1` = Big Endian)
     /// into `Vec<T>`.
     #[inline]
-    fn read<T, R: Read + BufRead + Seek>(
+    fn read<T, R: Read + Seek>(
         reader: &mut R,
         arch: u8,
         repeats: u8
@@ -220,7 +487,7 @@ impl Value {
     /// While `lossy` is optional, it's still `true` by default
     /// due to some FIT-files containing corrupt strings.
     #[inline]
-    fn from_utf8<R: Read + BufRead + Seek>(
+    fn from_utf8<R: Read + Seek>(
         reader: &mut R,
         arch: u8,
         repeats: u8,
@@ -238,7 +505,7 @@ impl Value {
     /// representing one or more
     /// values.
     #[inline]
-    pub fn new<R: Read + BufRead + Seek>(
+    pub fn new<R: Read + Seek>(
         reader: &mut R,
         field_def: &DefinitionField,
         architecture: u8
@@ -0,0 +1,579 @@
+//! FIT writer.
+//!
+//! Encodes data messages as a FIT file, to any `std::io::Write` destination.
+//! Definition messages are generated as needed, and local IDs (`0-15`)
+//! are reused for identical definitions, evicting the least recently used one
+//! when all are taken. Definitions from a parsed FIT file can be reused
+//! for faithful re-encoding, see `FitWriter::with_definitions()`.
+//!
+//! ```rs
+//! use fit_rs::{Fit, FitWriter};
+//! use std::{fs::File, path::Path};
+//!
+//! fn main() -> Result<(), fit_rs::FitError> {
+//!     let fit = Fit::new(Path::new("FITFILE.fit"))?;
+//!     let mut writer = FitWriter::create(Path::new("COPY.fit"))?
+//!         .with_definitions(fit.definitions.iter().cloned());
+//!     writer.write_all(fit.records.iter())?;
+//!     writer.finish()?;
+//!     Ok(())
+//! }
+//! ```
+
+use std::{
+    borrow::Borrow,
+    collections::{HashMap, HashSet},
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path
+};
+
+use crate::{
+    constants::{FIT_PROFILE_VERSION, FIT_PROTOCOL_VERSION},
+    errors::FitError,
+    types::{DeveloperDataId, FieldDescriptionMessage}
+};
+
+use super::{
+    crc::{crc16, crc16_byte},
+    BaseTypeDefinition,
+    DataField,
+    DataMessage,
+    DefinitionField,
+    DefinitionMessage,
+    FitHeader,
+    Value
+};
+
+/// Number of local IDs available for definitions.
+const LOCAL_IDS: usize = 16;
+
+/// Definition message contents, for matching data messages
+/// against definitions already written.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct DefinitionKey {
+    global: u16,
+    /// 0=LE, 1=BE
+    architecture: u8,
+    /// `(field definition number, size, base type)`
+    fields: Vec<(u8, u8, u8)>,
+    /// `(field number, size, developer data index)`
+    dev_fields: Vec<(u8, u8, u8)>,
+}
+
+/// Definition in use for a local ID.
+#[derive(Debug, Clone)]
+struct LocalDefinition {
+    key: DefinitionKey,
+    /// Number of data messages written at last use.
+    used: usize,
+    /// Index of the preserved definition written, if any.
+    index: Option<usize>,
+}
+
+/// FIT writer. Encodes data messages in the order written
+/// as a single FIT file.
+///
+/// Data is buffered until `FitWriter::finish()`,
+/// since the header holds the data size.
+///
+/// Data messages that reference a definition set via
+/// `FitWriter::with_definitions()` are encoded with that definition,
+/// i.e. with the same local ID, architecture, and field order,
+/// and with a compressed timestamp header if the original had one.
+/// Other data messages get a little-endian definition
+/// for each kind of data message.
+///
+/// Developer fields need a developer data index
+/// (`DataField::developer_data_index()`).
+/// Developer data ID (`207`) and field description (`206`)
+/// messages are written from field attributes, unless already written.
+/// Developer data IDs set via `FitWriter::with_developer_data_ids()`
+/// are written in full instead, since field attributes only hold the application ID.
+///
+/// Notes:
+/// - Data messages with fields that are not in their definition,
+///   e.g. fields derived via component expansion, get a new definition
+///   with derived fields written as regular fields.
+///   Re-parsing yields the same values, since expansion does not overwrite
+///   logged fields. For byte-identical output, parse with
+///   `FitReader::with_expansion(false)`.
+/// - Definitions not referenced by any data message are not written.
+pub struct FitWriter<W: Write> {
+    writer: W,
+    /// Header size, 12 or 14 bytes.
+    headersize: u8,
+    protocol: u8,
+    profile: u16,
+    /// Encoded definition and data messages.
+    data: Vec<u8>,
+    /// Preserved definitions by definition index.
+    definitions: HashMap<usize, DefinitionMessage>,
+    /// Definition for each local ID, if in use.
+    locals: Vec<Option<LocalDefinition>>,
+    /// Last full timestamp written, for compressed timestamp headers.
+    last_timestamp: Option<u32>,
+    /// Number of data messages written.
+    count: usize,
+    /// Developer data indeces with a developer data ID written.
+    developer_data_ids: HashSet<u8>,
+    /// Parsed developer data IDs by developer data index,
+    /// for writing developer data ID messages in full.
+    known_developer_data_ids: HashMap<u8, DeveloperDataId>,
+    /// Field descriptions written, as
+    /// `(developer data index, field number)`.
+    field_descriptions: HashSet<(u8, u8)>,
+}
+
+impl FitWriter<BufWriter<File>> {
+    /// Create FIT-file for writing.
+    /// Nothing is written until `FitWriter::finish()`.
+    pub fn create(path: &Path) -> Result<Self, FitError> {
+        Ok(Self::new(BufWriter::new(File::create(path)?)))
+    }
+}
+
+impl<W: Write> FitWriter<W> {
+    /// New FIT writer with a 14 byte header,
+    /// protocol version `FIT_PROTOCOL_VERSION`,
+    /// and profile version `FIT_PROFILE_VERSION`.
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            headersize: 14,
+            protocol: FIT_PROTOCOL_VERSION,
+            profile: FIT_PROFILE_VERSION,
+            data: Vec::new(),
+            definitions: HashMap::new(),
+            locals: vec![None; LOCAL_IDS],
+            last_timestamp: None,
+            count: 0,
+            developer_data_ids: HashSet::new(),
+            known_developer_data_ids: HashMap::new(),
+            field_descriptions: HashSet::new(),
+        }
+    }
+
+    /// Use header size, protocol and profile version from `header`,
+    /// e.g. that of a parsed FIT-file. Data size and CRC are
+    /// computed when writing. Header sizes other than 12 bytes
+    /// result in a 14 byte header with CRC.
+    pub fn with_header(self, header: FitHeader) -> Self {
+        Self {
+            headersize: match header.headersize {
+                12 => 12,
+                _ => 14
+            },
+            protocol: header.protocol,
+            profile: header.profile,
+            ..self
+        }
+    }
+
+    /// Encode data messages with the definitions they were parsed with,
+    /// see `DataMessage.definition` and `Fit.definitions`.
+    pub fn with_definitions(self, definitions: impl IntoIterator<Item = DefinitionMessage>) -> Self {
+        Self {
+            definitions: definitions.into_iter()
+                .map(|def| (def.index, def))
+                .collect(),
+            ..self
+        }
+    }
+
+    /// Write developer data ID (`207`) messages for developer fields
+    /// from `developer_data_ids`, e.g. `Fit::developer_data_ids()`,
+    /// i.e. with developer ID, manufacturer ID, and application version,
+    /// if these are not already written as regular data messages.
+    pub fn with_developer_data_ids(self, developer_data_ids: impl IntoIterator<Item = DeveloperDataId>) -> Self {
+        Self {
+            known_developer_data_ids: developer_data_ids.into_iter()
+                .map(|id| (id.developer_data_index, id))
+                .collect(),
+            ..self
+        }
+    }
+
+    /// Encode data message, preceded by a definition message
+    /// if none matches, and for developer fields
+    /// any missing developer data ID and field description.
+    pub fn write(&mut self, message: &DataMessage) -> Result<(), FitError> {
+        self.write_developer_data(message)?;
+
+        let (header, bytes) = match self.encode_preserved(message) {
+            Some((local, index, bytes, offset)) => {
+                self.use_preserved_definition(local, index);
+                match offset {
+                    // Compressed timestamp header, local ID 0-3
+                    Some(offset) => (0x80 | (local << 5) | offset, bytes),
+                    None => (local, bytes)
+                }
+            },
+            None => {
+                let (key, bytes) = Self::encode(message)?;
+                (self.local_id(key), bytes)
+            }
+        };
+
+        self.data.push(header);
+        self.data.extend(bytes);
+        self.count += 1;
+
+        if let Some(timestamp) = message.timestamp() {
+            self.last_timestamp = Some(timestamp);
+        }
+
+        // Keep track of developer data written as regular data messages
+        match message.global {
+            206 => if let Ok(descr) = FieldDescriptionMessage::new(message) {
+                self.field_descriptions.insert((descr.developer_data_index, descr.field_definition_number));
+            },
+            207 => if let Ok(id) = DeveloperDataId::new(message) {
+                self.developer_data_ids.insert(id.developer_data_index);
+            },
+            _ => ()
+        }
+
+        Ok(())
+    }
+
+    /// Encode all data messages in `messages` in order,
+    /// e.g. `Fit.records`.
+    pub fn write_all<I>(&mut self, messages: I) -> Result<(), FitError>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<DataMessage>
+    {
+        for message in messages {
+            self.write(message.borrow())?;
+        }
+        Ok(())
+    }
+
+    /// Returns number of data messages written so far,
+    /// including generated developer data messages.
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Returns `true` if no data messages have been written.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Write header, data, and file CRC, and return the inner writer.
+    /// The header CRC is only written for 14 byte headers.
+    pub fn finish(mut self) -> Result<W, FitError> {
+        let mut header: Vec<u8> = Vec::with_capacity(14);
+        header.push(self.headersize);
+        header.push(self.protocol);
+        header.extend(self.profile.to_le_bytes());
+        header.extend((self.data.len() as u32).to_le_bytes());
+        header.extend(b".FIT");
+        if self.headersize == 14 {
+            header.extend(crc16(&header).to_le_bytes());
+        }
+
+        // File CRC covers header and data
+        let crc = self.data.iter()
+            .fold(crc16(&header), |crc, byte| crc16_byte(crc, *byte));
+
+        self.writer.write_all(&header)?;
+        self.writer.write_all(&self.data)?;
+        self.writer.write_all(&crc.to_le_bytes())?;
+        self.writer.flush()?;
+
+        Ok(self.writer)
+    }
+
+    /// Returns a little-endian definition for `message`,
+    /// and field values as bytes.
+    fn encode(message: &DataMessage) -> Result<(DefinitionKey, Vec<u8>), FitError> {
+        let mut key = DefinitionKey {
+            global: message.global,
+            architecture: 0,
+            fields: Vec::with_capacity(message.fields.len()),
+            dev_fields: Vec::with_capacity(message.dev_fields.len()),
+        };
+        let mut bytes: Vec<u8> = Vec::new();
+
+        for field in message.fields.iter() {
+            let encoded = Self::encode_field(message.global, field)?;
+            key.fields.push((field.field_def_no(), encoded.len() as u8, field.data.base_type()));
+            bytes.extend(encoded);
+        }
+
+        for field in message.dev_fields.iter() {
+            let developer_data_index = Self::developer_data_index(field)?;
+            let encoded = Self::encode_field(message.global, field)?;
+            key.dev_fields.push((field.field_def_no(), encoded.len() as u8, developer_data_index));
+            bytes.extend(encoded);
+        }
+
+        Ok((key, bytes))
+    }
+
+    /// Returns field values as bytes.
+    fn encode_field(global: u16, field: &DataField) -> Result<Vec<u8>, FitError> {
+        let bytes = field.data.to_bytes(field.definition.size as usize);
+        match bytes.len() > u8::MAX as usize {
+            true => Err(FitError::InvalidFieldSize{
+                global,
+                field_def_no: field.field_def_no(),
+                size: bytes.len()
+            }),
+            false => Ok(bytes)
+        }
+    }
+
+    /// Returns local ID and definition index for the preserved definition
+    /// for `message`, field values as bytes, and the time offset
+    /// for a compressed timestamp header.
+    /// Returns `None` if there is no preserved definition,
+    /// or if fields do not match the definition.
+    fn encode_preserved(&self, message: &DataMessage) -> Option<(u8, usize, Vec<u8>, Option<u8>)> {
+        let definition = self.definitions.get(&message.definition?)?;
+        if definition.global != message.global
+            || definition.dev_fields.len() != message.dev_fields.len()
+        {
+            return None
+        }
+
+        let mut bytes: Vec<u8> = Vec::new();
+
+        for def in definition.fields.iter() {
+            let field = message.fields.iter()
+                .find(|f| f.field_def_no() == def.field_def_no)?;
+            bytes.extend(Self::encode_as(field, def, definition.is_big_endian())?);
+        }
+
+        for def in definition.dev_fields.iter() {
+            let developer_data_index = def.attributes.as_ref()
+                .and_then(|attr| attr.developer_data_index)?;
+            let field = message.dev_fields.iter()
+                .find(|f| f.field_def_no() == def.field_def_no
+                    && f.developer_data_index() == Some(developer_data_index))?;
+            bytes.extend(Self::encode_as(field, def, definition.is_big_endian())?);
+        }
+
+        // The only field not in the definition may be
+        // the timestamp from a compressed timestamp header
+        let offset = match message.fields.len().checked_sub(definition.fields.len())? {
+            0 => None,
+            1 => Some(self.time_offset(message, definition)?),
+            _ => return None
+        };
+
+        Some((definition.local, definition.index, bytes, offset))
+    }
+
+    /// Returns field values as bytes for field definition `def`,
+    /// or `None` if base type or size does not match.
+    fn encode_as(field: &DataField, def: &DefinitionField, big_endian: bool) -> Option<Vec<u8>> {
+        if field.data.base_type() & 0x1F != def.base_type.number() {
+            return None
+        }
+        let mut bytes = field.data.to_bytes(def.size as usize);
+        if bytes.len() != def.size as usize {
+            return None
+        }
+        if big_endian {
+            let base_len = BaseTypeDefinition::new(field.data.base_type()).base_len().unwrap_or(1);
+            bytes.chunks_mut(base_len as usize)
+                .for_each(|value| value.reverse());
+        }
+        Some(bytes)
+    }
+
+    /// Returns 5-bit time offset for a compressed timestamp header,
+    /// or `None` if `message` can not have had one.
+    fn time_offset(&self, message: &DataMessage, definition: &DefinitionMessage) -> Option<u8> {
+        if definition.local > 3 || definition.fields.iter().any(|f| f.field_def_no == 253) {
+            return None
+        }
+        let timestamp = message.timestamp()?;
+        match timestamp.checked_sub(self.last_timestamp?)? < 0x20 {
+            true => Some((timestamp & 0x1F) as u8),
+            false => None
+        }
+    }
+
+    fn developer_data_index(field: &DataField) -> Result<u8, FitError> {
+        field.developer_data_index()
+            .ok_or(FitError::MissingDeveloperDataIndex{field_number: field.field_def_no()})
+    }
+
+    /// Writes preserved definition with index `index` for local ID `local`,
+    /// unless it is the definition in use for that local ID.
+    fn use_preserved_definition(&mut self, local: u8, index: usize) {
+        let in_use = self.locals[local as usize].as_ref()
+            .is_some_and(|l| l.index == Some(index));
+
+        let key = match (in_use, self.definitions.get(&index)) {
+            (false, Some(definition)) => Some(DefinitionKey {
+                global: definition.global,
+                architecture: definition.architecture,
+                fields: definition.fields.iter()
+                    .map(|f| (f.field_def_no, f.size, f.base_type.as_u8()))
+                    .collect(),
+                dev_fields: definition.dev_fields.iter()
+                    .map(|f| (
+                        f.field_def_no,
+                        f.size,
+                        f.attributes.as_ref().and_then(|attr| attr.developer_data_index).unwrap_or_default()
+                    ))
+                    .collect(),
+            }),
+            _ => None
+        };
+
+        if let Some(key) = key {
+            self.write_definition(local, &key);
+            self.locals[local as usize] = Some(LocalDefinition {
+                key,
+                used: self.count,
+                index: Some(index)
+            });
+        }
+
+        if let Some(l) = self.locals[local as usize].as_mut() {
+            l.used = self.count;
+        }
+    }
+
+    /// Returns local ID for `key`, and writes a definition message
+    /// if `key` does not match the definition for any local ID in use.
+    fn local_id(&mut self, key: DefinitionKey) -> u8 {
+        let matching = self.locals.iter()
+            .position(|l| l.as_ref().is_some_and(|l| l.key == key));
+        if let Some(local) = matching {
+            if let Some(l) = self.locals[local].as_mut() {
+                l.used = self.count;
+            }
+            return local as u8
+        }
+
+        // Use a free local ID, or evict least recently used definition
+        let local = self.locals.iter()
+            .position(Option::is_none)
+            .or_else(|| self.locals.iter()
+                .enumerate()
+                .min_by_key(|(_, l)| l.as_ref().map(|l| l.used))
+                .map(|(i, _)| i)
+            )
+            .unwrap_or_default();
+
+        self.write_definition(local as u8, &key);
+        self.locals[local] = Some(LocalDefinition {
+            key,
+            used: self.count,
+            index: None
+        });
+
+        local as u8
+    }
+
+    fn write_definition(&mut self, local: u8, key: &DefinitionKey) {
+        let header = match key.dev_fields.is_empty() {
+            true => 0x40 | local,
+            false => 0x60 | local, // developer data flag
+        };
+        self.data.push(header);
+        self.data.push(0); // reserved
+        self.data.push(key.architecture);
+        match key.architecture {
+            1 => self.data.extend(key.global.to_be_bytes()),
+            _ => self.data.extend(key.global.to_le_bytes()),
+        }
+        self.data.push(key.fields.len() as u8);
+        for (field_def_no, size, base_type) in key.fields.iter() {
+            self.data.extend([*field_def_no, *size, *base_type]);
+        }
+        if !key.dev_fields.is_empty() {
+            self.data.push(key.dev_fields.len() as u8);
+            for (field_number, size, developer_data_index) in key.dev_fields.iter() {
+                self.data.extend([*field_number, *size, *developer_data_index]);
+            }
+        }
+    }
+
+    /// Write developer data ID (`207`) and field description (`206`)
+    /// messages for developer fields in `message`, unless already written.
+    fn write_developer_data(&mut self, message: &DataMessage) -> Result<(), FitError> {
+        for field in message.dev_fields.iter() {
+            let developer_data_index = Self::developer_data_index(field)?;
+
+            if !self.developer_data_ids.contains(&developer_data_index) {
+                let fields = match self.known_developer_data_ids.get(&developer_data_index) {
+                    Some(id) => Self::developer_data_id_fields(id),
+                    None => {
+                        let mut fields = Vec::new();
+                        if let Some(application_id) = field.application_id() {
+                            fields.push(DataField::from_value(1, Value::Byte(application_id.to_vec())));
+                        }
+                        fields.push(DataField::from_value(3, Value::Uint8(vec![developer_data_index])));
+                        fields
+                    }
+                };
+                self.write(&Self::message(207, fields))?;
+            }
+
+            if !self.field_descriptions.contains(&(developer_data_index, field.field_def_no())) {
+                let mut fields = vec![
+                    DataField::from_value(0, Value::Uint8(vec![developer_data_index])),
+                    DataField::from_value(1, Value::Uint8(vec![field.field_def_no()])),
+                    DataField::from_value(2, Value::Uint8(vec![field.data.base_type()])),
+                    DataField::from_value(3, Value::String(field.name().unwrap_or_default().to_owned())),
+                ];
+                // Field descriptions only hold integer scale and offset
+                if let Some(scale) = field.scale().filter(|s| s.fract() == 0. && (1. ..= 254.).contains(s)) {
+                    fields.push(DataField::from_value(6, Value::Uint8(vec![scale as u8])));
+                }
+                if let Some(offset) = field.offset().filter(|o| o.fract() == 0. && (-128. ..= 126.).contains(o)) {
+                    fields.push(DataField::from_value(7, Value::Sint8(vec![offset as i8])));
+                }
+                if let Some(units) = field.units() {
+                    fields.push(DataField::from_value(8, Value::String(units.to_owned())));
+                }
+                if let Some(attr) = field.attributes.as_ref() {
+                    if let Some(native_mesg_num) = attr.native_mesg_num {
+                        fields.push(DataField::from_value(14, Value::Uint16(vec![native_mesg_num])));
+                    }
+                    if let Some(native_field_num) = attr.native_field_num {
+                        fields.push(DataField::from_value(15, Value::Uint8(vec![native_field_num])));
+                    }
+                }
+                self.write(&Self::message(206, fields))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Fields for a developer data ID (`207`) message.
+    fn developer_data_id_fields(id: &DeveloperDataId) -> Vec<DataField> {
+        let mut fields = Vec::new();
+        if let Some(developer_id) = id.developer_id.as_ref() {
+            fields.push(DataField::from_value(0, Value::Byte(developer_id.to_owned())));
+        }
+        if let Some(application_id) = id.application_id {
+            fields.push(DataField::from_value(1, Value::Byte(application_id.to_vec())));
+        }
+        if let Some(manufacturer_id) = id.manufacturer_id {
+            fields.push(DataField::from_value(2, Value::Uint16(vec![manufacturer_id])));
+        }
+        fields.push(DataField::from_value(3, Value::Uint8(vec![id.developer_data_index])));
+        if let Some(application_version) = id.application_version {
+            fields.push(DataField::from_value(4, Value::Uint32(vec![application_version])));
+        }
+        fields
+    }
+
+    fn message(global: u16, fields: Vec<DataField>) -> DataMessage {
+        DataMessage {
+            global,
+            fields,
+            ..DataMessage::default()
+        }
+    }
+}
@@ -1,5 +1,5 @@
 //! Crate for reading Garmin FIT-files with additional Garmin VIRB specific functionality.
-//! Custom developer data is supported, but not compressed time stamp headers.
+//! Custom developer data and compressed time stamp headers are supported.
 //! Most data is only available in a fairly raw form, as it was stored in the FIT-file,
 //! and needs to be processed further.
 //!
@@ -34,6 +34,15 @@
 //!     Ok(())
 //! }
 //! ```
+//!
+//! Large FIT-files, or FIT-data from sources other than files, such as stdin,
+//! can be streamed one data message at a time via `FitReader`.
+//! `FitWriter` encodes data messages as a FIT-file, see also `Fit::write()`.
+//!
+//! With the `serde` feature, the data model, e.g. `Fit`, `DataMessage`, `Value`,
+//! and the typed messages, implements `Serialize` and `Deserialize`,
+//! so that parsed FIT-files can be cached or sent elsewhere without re-parsing.
+//! See `Value` for its JSON shape.
 
 mod errors;
 mod fit;
@@ -46,10 +55,35 @@ mod constants;
 pub use fit::{
     Fit,
     FitHeader,
+    FitCrc,
+    CrcStatus,
+    crc16,
+    FitSegment,
+    FitFile,
+    FileView,
+    ActivityFile,
+    CourseFile,
+    MonitoringFile,
+    SegmentFile,
+    SettingsFile,
+    TotalsFile,
+    WeightFile,
+    WorkoutFile,
+    TimestampMode,
+    FitReader,
+    FitWriter,
+    FitFilter,
+    FieldPredicate,
+    Diagnostic,
+    DiagnosticKind,
     BaseTypeDefinition,
     DataField,
+    DataFieldDisplay,
     DataMessage,
     DataFieldAttributes,
+    NativePrecedence,
+    PhysicalValue,
+    UnitSystem,
     DefinitionField,
     DefinitionMessage,
     Value
@@ -63,9 +97,12 @@ pub use virb::VirbSession;
 
 // FIT message type structs, these are more accessible via
 // `Fit` methods.
+pub use types::{Activity, Lap, Session};
 pub use types::CameraEvent;
+pub use types::DeveloperDataId;
+pub use types::{DeviceInfo, EventMessage, Hrv};
 pub use types::FieldDescriptionMessage;
-pub use types::{GpsMetadata, FitPoint};
+pub use types::{GpsMetadata, FitPoint, FitPointDisplay};
 pub use types::Record;
 pub use types::{
     SensorCalibration,
@@ -73,11 +110,34 @@ pub use types::{
     SensorType
 };
 pub use types::TimestampCorrelation;
-pub use constants::FIT_DEFAULT_DATETIME;
+pub use types::{TimeDrift, TimeModel, TimePoint, TimePointSource};
+pub use constants::{
+    FIT_DEFAULT_DATETIME,
+    FIT_MIN_ABSOLUTE_TIMESTAMP,
+    FIT_PROFILE_VERSION,
+    FIT_PROTOCOL_VERSION
+};
 pub use profile::{
+    FitComponent,
     FitFieldType,
+    FitSubfield,
     FitMessageType
 };
+// FIT enum types, from the "Types" sheet in Profile.xlsx
+pub use profile::{
+    BatteryStatus,
+    CameraEventType,
+    Event,
+    EventType,
+    FileType,
+    Manufacturer,
+    SourceType,
+    Sport,
+    SubSport,
+    TimerTrigger
+};
+// All FIT enum types and their labels, from the "Types" sheet in Profile.xlsx
+pub use profile::types as profile_types;
 
 // Errors
 pub use errors::FitError;
\ No newline at end of file
@@ -1,23 +1,45 @@
 //! FIT message types as described in [FIT SDK](https://developer.garmin.com/fit/overview/) Profile.xslx.
 
-use std::collections::HashMap;
+use crate::fit::{physical, DataField};
 
-use super::profile::get_messagetype;
+use super::profile::MESSAGES;
+use super::types;
 
 /// FIT Message Type, from Messages sheet in Profile.xlsx
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 pub struct FitMessageType {
     /// mesg_num in Profile.xlsx "Types" sheet
     pub global_id: u16,
     /// first column in Profile.xlsx "Messages" sheet
-    pub name: String,
-    /// Key: field_def_no, Value: Fields as specified in Profile.xlsx "Messages" sheet
-    pub fields: HashMap<u8, FitFieldType>,
+    pub name: &'static str,
+    /// Fields as specified in Profile.xlsx "Messages" sheet,
+    /// sorted on field definition number.
+    pub fields: &'static [FitFieldType],
 }
 
 impl FitMessageType {
-    pub fn get(global_id: u16) -> Self {
-        get_messagetype(global_id)
+    /// Returns message type for FIT global ID,
+    /// or `None` if not listed in Profile.xlsx.
+    pub fn get(global_id: u16) -> Option<&'static Self> {
+        MESSAGES.get(&global_id)
+    }
+
+    /// Returns field with field definition number `field_def_no`,
+    /// or `None` if not listed in Profile.xlsx.
+    pub fn field(&self, field_def_no: u8) -> Option<&'static FitFieldType> {
+        self.fields.binary_search_by_key(&field_def_no, |f| f.field_def_no)
+            .ok()
+            .map(|i| &self.fields[i])
+    }
+
+    /// Applies scale and offset for field `field_def_no`
+    /// in message type `global` to `raw`, see `FitFieldType::to_physical()`.
+    /// Returns `raw` as is if the field is not listed in Profile.xlsx.
+    pub(crate) fn to_physical(global: u16, field_def_no: u8, raw: f64) -> f64 {
+        match Self::get(global).and_then(|mt| mt.field(field_def_no)) {
+            Some(field) => field.to_physical(raw),
+            None => raw
+        }
     }
 }
 
@@ -26,24 +48,174 @@ impl FitMessageType {
 /// Note that not some fields may not have scale/offset,
 /// but still require further processing, e.g. latitude/longitude
 /// in gps_metadata/160.
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 pub struct FitFieldType {
     pub field_def_no: u8, // numerical, some are unfortunately strings in Profile.xlsx
-    pub name: String,
-    pub scale: Option<u32>,  // numerical
-    pub offset: Option<i32>, // numerical
-    pub units: Option<String>,
+    pub name: &'static str,
+    /// "Field Type" column, i.e. a base type such as `uint16`,
+    /// or a type in Profile.xlsx "Types" sheet such as `sport`.
+    /// Empty if not specified.
+    pub field_type: &'static str,
+    /// FIT base type number for `field_type`, e.g. `6` for `uint32`,
+    /// via the "Types" sheet for types such as `sport`.
+    /// `None` if `field_type` is not specified.
+    pub base_type: Option<u8>,
+    /// Value names by value, if `field_type` is
+    /// listed in Profile.xlsx "Types" sheet.
+    pub labels: Option<&'static phf::Map<u32, &'static str>>,
+    pub scale: Option<f64>,  // numerical
+    pub offset: Option<f64>, // numerical
+    pub units: Option<&'static str>,
+    /// Bit-packed components, in the order they are packed,
+    /// starting with the least significant bit.
+    /// Empty if the field has no components.
+    pub components: &'static [FitComponent],
+    /// Dynamic subfields, that change the field's meaning
+    /// depending on the value of another field in the same message.
+    /// Empty if the field has no subfields.
+    pub subfields: &'static [FitSubfield],
 }
 
 impl FitFieldType {
-    /// Called by `profile::get_messagetype()`
-    pub fn new(ft: (u8, String, Option<u32>, Option<i32>, Option<String>)) -> Self {
-        FitFieldType {
-            field_def_no: ft.0,
-            name: ft.1,
-            scale: ft.2,
-            offset: ft.3,
-            units: ft.4,
+    /// Returns the first subfield with a reference field
+    /// that matches a field in `fields`, i.e. the other fields
+    /// in the same data message.
+    /// Returns `None` if no subfield applies,
+    /// in which case the field itself applies.
+    pub fn subfield(&self, fields: &[DataField]) -> Option<&'static FitSubfield> {
+        self.subfields.iter()
+            .find(|sub| sub.is_match(fields))
+    }
+
+    /// Returns bit-packed components for the field,
+    /// or for the subfield that applies, if any.
+    pub fn components(&self, fields: &[DataField]) -> &'static [FitComponent] {
+        match self.subfield(fields) {
+            Some(sub) => sub.components,
+            None => self.components
         }
     }
+
+    /// Returns the name of `value` if the field type
+    /// is listed in Profile.xlsx "Types" sheet,
+    /// e.g. `cycling` for `sport` and `2`.
+    pub fn label(&self, value: u64) -> Option<&'static str> {
+        types::label(self.labels, value)
+    }
+
+    /// Applies scale and offset to `raw`,
+    /// see `DataField::values_f64()`.
+    pub(crate) fn to_physical(&self, raw: f64) -> f64 {
+        physical::to_physical(raw, self.scale, self.offset, self.units)
+    }
+}
+
+/// Dynamic subfield as specified in Profile.xlsx "Messages" sheet,
+/// e.g. `garmin_product` for `product` in `device_info/23`,
+/// if `manufacturer` is Garmin.
+#[derive(Debug)]
+pub struct FitSubfield {
+    pub name: &'static str,
+    /// "Field Type" column, see `FitFieldType.field_type`.
+    pub field_type: &'static str,
+    /// Value names by value, see `FitFieldType.labels`.
+    pub labels: Option<&'static phf::Map<u32, &'static str>>,
+    pub scale: Option<f64>,
+    pub offset: Option<f64>,
+    pub units: Option<&'static str>,
+    /// Reference fields as `(field_def_no, value)`.
+    /// The subfield applies if any reference field
+    /// in the same data message has the specified value.
+    pub references: &'static [(u8, u32)],
+    /// Bit-packed components, see `FitFieldType.components`.
+    pub components: &'static [FitComponent],
+}
+
+impl FitSubfield {
+    /// Returns `true` if any reference field in `fields`
+    /// has the specified value.
+    pub fn is_match(&self, fields: &[DataField]) -> bool {
+        self.references.iter()
+            .any(|(field_def_no, value)| fields.iter()
+                .any(|f| f.field_def_no() == *field_def_no && f.data.as_u64() == Some(*value as u64))
+            )
+    }
+
+    /// Returns the name of `value`, see `FitFieldType::label()`.
+    pub fn label(&self, value: u64) -> Option<&'static str> {
+        types::label(self.labels, value)
+    }
+}
+
+/// Field component as specified in Profile.xlsx "Messages" sheet
+/// ("Components", "Scale", "Offset", "Bits", "Accumulate" columns).
+/// Describes a range of bits in a field that expands
+/// into another field in the same message,
+/// e.g. `speed` into `enhanced_speed` for `record/20`.
+#[derive(Debug, Clone, Copy)]
+pub struct FitComponent {
+    /// Field definition number for the destination field.
+    pub field_def_no: u8,
+    /// Number of bits.
+    pub bits: u8,
+    /// Scale for the bits, which may differ
+    /// from that of the destination field.
+    pub scale: Option<f64>,
+    /// Offset for the bits, which may differ
+    /// from that of the destination field.
+    pub offset: Option<f64>,
+    /// Bits represent a rolling value that
+    /// must be accumulated over consecutive messages,
+    /// e.g. `distance` in `compressed_speed_distance`.
+    pub accumulate: bool,
+}
+
+impl FitComponent {
+    pub const fn new(field_def_no: u8, bits: u8, scale: Option<f64>, offset: Option<f64>, accumulate: bool) -> Self {
+        Self {
+            field_def_no,
+            bits,
+            scale,
+            offset,
+            accumulate,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fit::Value;
+
+    use super::*;
+
+    /// `device_info/23` fields `manufacturer` and `product`.
+    fn device_info(manufacturer: u16) -> Vec<DataField> {
+        vec![
+            DataField::from_value(2, Value::Uint16(vec![manufacturer])),
+            DataField::from_value(4, Value::Uint16(vec![3121])),
+        ]
+    }
+
+    #[test]
+    fn subfield_is_match() {
+        let product = FitMessageType::get(23).and_then(|mt| mt.field(4)).unwrap();
+        let garmin_product = product.subfields.iter()
+            .find(|sub| sub.name == "garmin_product")
+            .unwrap();
+
+        // garmin, dynastream, dynastream_oem, tacx
+        for manufacturer in [1, 15, 13, 89] {
+            assert!(garmin_product.is_match(&device_info(manufacturer)));
+            assert_eq!(product.subfield(&device_info(manufacturer)).map(|sub| sub.name), Some("garmin_product"));
+        }
+
+        // favero_electronics
+        assert!(!garmin_product.is_match(&device_info(263)));
+        assert_eq!(product.subfield(&device_info(263)).map(|sub| sub.name), Some("favero_product"));
+
+        // development, no subfield applies
+        assert_eq!(product.subfield(&device_info(255)).map(|sub| sub.name), None);
+        // No reference field
+        assert!(product.subfield(&device_info(1)[1 ..]).is_none());
+    }
 }
@@ -2,5 +2,18 @@
 
 pub mod message_type;
 pub mod profile;
+pub mod types;
 
-pub use message_type::{FitFieldType, FitMessageType};
\ No newline at end of file
+pub use message_type::{FitComponent, FitFieldType, FitMessageType, FitSubfield};
+pub use types::{
+    BatteryStatus,
+    CameraEventType,
+    Event,
+    EventType,
+    FileType,
+    Manufacturer,
+    SourceType,
+    Sport,
+    SubSport,
+    TimerTrigger
+};
\ No newline at end of file
@@ -0,0 +1,21 @@
+//! FIT types from the "Types" sheet in the [FIT SDK](https://developer.garmin.com/fit/overview/) Profile.xlsx,
+//! generated from `profile/types.csv` at build time, see `build.rs`.
+//!
+//! Enums are generated for all types with an integer base type,
+//! e.g. `Sport` for `sport`, with `from_*`, `as_*`, `name()`, and `Display`.
+//! Value names for types that fields refer to are also available
+//! as labels via `FitFieldType::label()`.
+//! Values not covered return `None` rather than an error,
+//! since newer devices may log values added in later SDK versions.
+
+use std::fmt;
+
+include!(concat!(env!("OUT_DIR"), "/types.rs"));
+
+/// Returns the name of `value` in `labels`, i.e. the value names for a type,
+/// e.g. `cycling` for `sport` and `2`.
+pub(crate) fn label(labels: Option<&'static phf::Map<u32, &'static str>>, value: u64) -> Option<&'static str> {
+    let value = u32::try_from(value).ok()?;
+    labels.and_then(|values| values.get(&value))
+        .copied()
+}
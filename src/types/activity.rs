@@ -0,0 +1,84 @@
+//! FIT `activity` message (global ID 34).
+//!
+//! Summary of the whole activity, such as number of sessions (`1/num_sessions`),
+//! and local time (`5/local_timestamp`).
+
+use std::ops::Range;
+
+use crate::{fit::DataMessage, Fit, FitError};
+use crate::{Event, EventType};
+
+/// Activity summary, global ID 34.
+/// Values are physical values, i.e. with scale and offset
+/// from the FIT profile applied.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Activity {
+    /// Field def no: 0, `s`
+    pub total_timer_time: Option<f64>,
+    /// Field def no: 1
+    pub num_sessions: Option<u16>,
+    /// Field def no: 2
+    pub r#type: Option<u8>,
+    /// Field def no: 3
+    pub event: Option<Event>,
+    /// Field def no: 4
+    pub event_type: Option<EventType>,
+    /// Field def no: 5, local time in seconds
+    pub local_timestamp: Option<u32>,
+    /// Field def no: 6
+    pub event_group: Option<u8>,
+    /// Field def no: 253, FIT timestamp in seconds
+    pub timestamp: Option<u32>,
+    pub(crate) index: usize,
+}
+
+impl Activity {
+    /// Parse `DataMessage` as `Activity` (`activity/34`).
+    /// Fields that are not logged, or only contain invalid values,
+    /// are `None`.
+    pub fn new(data_message: &DataMessage) -> Result<Self, FitError> {
+        let global_id = 34_u16; // activity
+
+        if data_message.global != global_id {
+            return Err(FitError::UnexpectedMessageType{expected: global_id, got: data_message.global})
+        }
+
+        let mut activity = Self {
+            index: data_message.index,
+            ..Self::default()
+        };
+
+        for field in data_message.fields.iter() {
+            match field.field_def_no() {
+                0 => activity.total_timer_time = field.profile_value_f64(global_id),
+                1 => activity.num_sessions = field.value_as(),
+                2 => activity.r#type = field.value_as(),
+                3 => activity.event = field.value_as().and_then(Event::from_u8),
+                4 => activity.event_type = field.value_as().and_then(EventType::from_u8),
+                5 => activity.local_timestamp = field.value_as(),
+                6 => activity.event_group = field.value_as(),
+                253 => activity.timestamp = field.value_as(),
+                _ => (),
+            }
+        }
+
+        Ok(activity)
+    }
+
+    /// Extract `Activity` (`activity/34`) from FIT.
+    /// Returns the first if several are logged.
+    pub fn from_fit(
+        fit: &Fit,
+        range: Option<&Range<usize>>, // slice indeces for session
+    ) -> Result<Self, FitError> {
+        let global = 34_u16;
+
+        let range = range.cloned().unwrap_or(0 .. fit.len());
+
+        fit.records[range].iter() // logged once, usually last
+            .find(|rec| rec.global == global)
+            .map(Self::new)
+            .ok_or_else(|| FitError::ErrorParsingMessage(global))?
+    }
+}
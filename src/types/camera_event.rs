@@ -6,13 +6,14 @@ use time::Duration;
 
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 
-use crate::Fit;
+use crate::{Fit, CameraEventType};
 use crate::{FitError, fit::DataMessage};
 
 /// VIRB only.
 /// Parsed `camera_event` data message, global id 161.
 /// Contains UUID for the corresponding video file (present in both MP4 and GLV).
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CameraEvent {
     /// Field definition number 253: seconds
     pub timestamp: u32,
@@ -86,6 +87,12 @@ impl CameraEvent {
             .collect()
     }
 
+    /// Returns `camera_event_type` as `CameraEventType`,
+    /// or `None` if the value is not covered.
+    pub fn event_type(&self) -> Option<CameraEventType> {
+        CameraEventType::from_u8(self.camera_event_type)
+    }
+
     /// Converts `timestamp` and `timestamp_ms`
     /// fields into a single `time::Duration` object.
     pub fn to_duration(&self) -> Duration {
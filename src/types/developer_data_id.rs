@@ -0,0 +1,105 @@
+//! FIT developer data ID message (global ID 207). Identifies the application,
+//! e.g. a Connect IQ app, that logged developer data for a developer data index.
+
+use std::ops::Range;
+
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+
+use crate::fit::DataMessage;
+use crate::FitError;
+use crate::Fit;
+
+/// Developer Data ID Message, global id 207.
+/// Links a developer data index, as referenced by
+/// field descriptions (`206`) and developer fields,
+/// to the application that logged the data.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DeveloperDataId {
+    /// Field def no: 0, byte array
+    pub developer_id: Option<Vec<u8>>,
+    /// Field def no: 1, 16 byte application UUID,
+    /// e.g. the Connect IQ app ID.
+    pub application_id: Option<[u8; 16]>,
+    /// Field def no: 2, see `Manufacturer`
+    pub manufacturer_id: Option<u16>,
+    /// Field def no: 3, index referenced by field descriptions (`206`)
+    pub developer_data_index: u8,
+    /// Field def no: 4
+    pub application_version: Option<u32>,
+    pub(crate) index: usize
+}
+
+impl DeveloperDataId {
+    /// Returns a `DeveloperDataId`/207 from a restructured `DataMessage`.
+    /// Error handling is for determining if a required field could not be assigned
+    /// , i.e. was not present in input data.
+    pub fn new(data_message: &DataMessage) -> Result<Self, FitError> {
+        let global_id = 207;
+
+        if data_message.global != global_id {
+            return Err(FitError::UnexpectedMessageType{expected: global_id, got: data_message.global})
+        }
+
+        let mut developer_id: Option<Vec<u8>> = None; // id: 0
+        let mut application_id: Option<Vec<u8>> = None; // id: 1
+        let mut manufacturer_id: Option<u16> = None; // id: 2
+        let mut developer_data_index: Option<u8> = None; // id: 3
+        let mut application_version: Option<u32> = None; // id: 4
+
+        for field in data_message.fields.iter() {
+            match field.field_def_no() {
+                0 => developer_id = field.data.as_ref().into(),
+                1 => application_id = field.data.as_ref().into(),
+                2 => manufacturer_id = field.data.as_ref().into(),
+                3 => developer_data_index = field.data.as_ref().into(),
+                4 => application_version = field.data.as_ref().into(),
+                _ => (),
+            }
+        }
+
+        Ok(Self {
+            developer_id,
+            application_id: application_id
+                .and_then(|id| <[u8; 16]>::try_from(id).ok()),
+            manufacturer_id,
+            developer_data_index: developer_data_index
+                .ok_or_else(|| data_message.field_error(3))?,
+            application_version,
+            index: data_message.index
+        })
+    }
+
+    /// Parses all developer_data_id/207 and returns these in a more accessible form.
+    pub fn from_fit(
+        fit: &Fit,
+        range: Option<Range<usize>>, // slice indeces for session
+    ) -> Result<Vec<Self>, FitError> {
+        let global = 207_u16;
+
+        let range = range.unwrap_or(0 .. fit.len());
+
+        fit.records[range].par_iter()
+            .filter(|rec| rec.global == global)
+            .map(Self::new)
+            .collect()
+    }
+
+    /// Returns application ID formatted as a UUID,
+    /// e.g. `6957fe68-83fe-4ed6-8613-413f70624bb5`,
+    /// with bytes in logged order.
+    pub fn application_uuid(&self) -> Option<String> {
+        self.application_id.map(|id| format_uuid(&id))
+    }
+}
+
+/// Formats 16 bytes as a hyphenated, lower case UUID.
+pub(crate) fn format_uuid(bytes: &[u8; 16]) -> String {
+    bytes.iter()
+        .enumerate()
+        .map(|(i, b)| match i {
+            4 | 6 | 8 | 10 => format!("-{b:02x}"),
+            _ => format!("{b:02x}")
+        })
+        .collect()
+}
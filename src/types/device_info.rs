@@ -0,0 +1,141 @@
+//! FIT `device_info` message (global ID 23).
+//!
+//! Logged for the recording device (device index `0`, i.e. creator),
+//! and for each connected sensor, such as heart rate monitors
+//! and power meters, with battery status (`11/battery_status`)
+//! and sensor type (`1/device_type`).
+
+use std::ops::Range;
+
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+
+use crate::{fit::DataMessage, Fit, FitError};
+use crate::{BatteryStatus, FitMessageType, SourceType};
+
+/// Device info, global ID 23.
+/// Values are physical values, i.e. with scale and offset
+/// from the FIT profile applied.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DeviceInfo {
+    /// Field def no: 0, `0` for the recording device (creator)
+    pub device_index: Option<u8>,
+    /// Field def no: 1, sensor type. Meaning depends on `source_type`,
+    /// e.g. `antplus_device_type` for ANT+, see `DeviceInfo::device_type_name()`.
+    pub device_type: Option<u8>,
+    /// Field def no: 2, see `Manufacturer`
+    pub manufacturer: Option<u16>,
+    /// Field def no: 3
+    pub serial_number: Option<u32>,
+    /// Field def no: 4
+    pub product: Option<u16>,
+    /// Field def no: 5
+    pub software_version: Option<f64>,
+    /// Field def no: 6
+    pub hardware_version: Option<u8>,
+    /// Field def no: 7, `s`
+    pub cum_operating_time: Option<f64>,
+    /// Field def no: 10, `V`
+    pub battery_voltage: Option<f64>,
+    /// Field def no: 11
+    pub battery_status: Option<BatteryStatus>,
+    /// Field def no: 18
+    pub sensor_position: Option<u8>,
+    /// Field def no: 19
+    pub descriptor: Option<String>,
+    /// Field def no: 20
+    pub ant_transmission_type: Option<u8>,
+    /// Field def no: 21
+    pub ant_device_number: Option<u16>,
+    /// Field def no: 22
+    pub ant_network: Option<u8>,
+    /// Field def no: 25
+    pub source_type: Option<SourceType>,
+    /// Field def no: 27
+    pub product_name: Option<String>,
+    /// Field def no: 253, FIT timestamp in seconds
+    pub timestamp: Option<u32>,
+    pub(crate) index: usize,
+}
+
+impl DeviceInfo {
+    /// Parse `DataMessage` as `DeviceInfo` (`device_info/23`).
+    /// Fields that are not logged, or only contain invalid values,
+    /// are `None`.
+    pub fn new(data_message: &DataMessage) -> Result<Self, FitError> {
+        let global_id = 23_u16; // device_info
+
+        if data_message.global != global_id {
+            return Err(FitError::UnexpectedMessageType{expected: global_id, got: data_message.global})
+        }
+
+        let mut device_info = Self {
+            index: data_message.index,
+            ..Self::default()
+        };
+
+        for field in data_message.fields.iter() {
+            match field.field_def_no() {
+                0 => device_info.device_index = field.value_as(),
+                1 => device_info.device_type = field.value_as(),
+                2 => device_info.manufacturer = field.value_as(),
+                3 => device_info.serial_number = field.value_as(),
+                4 => device_info.product = field.value_as(),
+                5 => device_info.software_version = field.profile_value_f64(global_id),
+                6 => device_info.hardware_version = field.value_as(),
+                7 => device_info.cum_operating_time = field.profile_value_f64(global_id),
+                10 => device_info.battery_voltage = field.profile_value_f64(global_id),
+                11 => device_info.battery_status = field.value_as().and_then(BatteryStatus::from_u8),
+                18 => device_info.sensor_position = field.value_as(),
+                19 => device_info.descriptor = field.data.as_ref().into(),
+                20 => device_info.ant_transmission_type = field.value_as(),
+                21 => device_info.ant_device_number = field.value_as(),
+                22 => device_info.ant_network = field.value_as(),
+                25 => device_info.source_type = field.value_as().and_then(SourceType::from_u8),
+                27 => device_info.product_name = field.data.as_ref().into(),
+                253 => device_info.timestamp = field.value_as(),
+                _ => (),
+            }
+        }
+
+        Ok(device_info)
+    }
+
+    /// Extract all `DeviceInfo` (`device_info/23`) from FIT, in logging order.
+    pub fn from_fit(
+        fit: &Fit,
+        range: Option<&Range<usize>>, // slice indeces for session
+    ) -> Result<Vec<Self>, FitError> {
+        let global = 23_u16;
+
+        let range = range.cloned().unwrap_or(0 .. fit.len());
+
+        fit.records[range].par_iter()
+            .filter(|rec| rec.global == global)
+            .map(Self::new)
+            .collect()
+    }
+
+    /// Returns the name of `device_type` for `source_type`,
+    /// i.e. the sensor type, e.g. `heart_rate` or `bike_power`.
+    /// Returns `None` if either is not logged, and for
+    /// source types without a type list, such as `ant`.
+    pub fn device_type_name(&self) -> Option<&'static str> {
+        let (device_type, source_type) = match (self.device_type, self.source_type) {
+            (Some(d), Some(s)) => (d, s),
+            _ => return None
+        };
+        // Subfield for `device_type` that references `source_type` (25)
+        FitMessageType::get(23)
+            .and_then(|mt| mt.field(1))
+            .and_then(|f| f.subfields.iter()
+                .find(|sub| sub.references.contains(&(25, source_type.as_u8() as u32)))
+            )
+            .and_then(|sub| sub.label(device_type as u64))
+    }
+
+    /// Returns `true` for the recording device, i.e. device index `0`.
+    pub fn is_creator(&self) -> bool {
+        self.device_index == Some(0)
+    }
+}
@@ -0,0 +1,159 @@
+//! FIT `event` message (global ID 21).
+//!
+//! Logged for e.g. timer start/stop (`timer`), gear changes
+//! (`front_gear_change`, `rear_gear_change`), and power down.
+//! What `3/data` means depends on `0/event`,
+//! e.g. `timer_trigger` for `timer`.
+
+use std::ops::Range;
+
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+
+use crate::{fit::DataMessage, Fit, FitError};
+use crate::{Event, EventType, FitMessageType, TimerTrigger};
+
+/// Event message, global ID 21.
+/// Named `EventMessage` to avoid a clash with the `Event` type,
+/// which is the value of `event`.
+///
+/// `data` is decoded according to `event` for timer,
+/// battery, sport point and gear change events.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EventMessage {
+    /// Field def no: 0
+    pub event: Option<Event>,
+    /// Field def no: 1
+    pub event_type: Option<EventType>,
+    /// Field def no: 3, raw value, meaning depends on `event`.
+    /// Includes `2/data16` if only that is logged.
+    pub data: Option<u32>,
+    /// Field def no: 4
+    pub event_group: Option<u8>,
+    /// Field def no: 7, or from `data` for `sport_point`
+    pub score: Option<u16>,
+    /// Field def no: 8, or from `data` for `sport_point`
+    pub opponent_score: Option<u16>,
+    /// Field def no: 9, or from `data` for gear changes
+    pub front_gear_num: Option<u8>,
+    /// Field def no: 10, or from `data` for gear changes
+    pub front_gear: Option<u8>,
+    /// Field def no: 11, or from `data` for gear changes
+    pub rear_gear_num: Option<u8>,
+    /// Field def no: 12, or from `data` for gear changes
+    pub rear_gear: Option<u8>,
+    /// Field def no: 13
+    pub device_index: Option<u8>,
+    /// Field def no: 21
+    pub radar_threat_level_max: Option<u8>,
+    /// Field def no: 22
+    pub radar_threat_count: Option<u8>,
+    /// Field def no: 253, FIT timestamp in seconds
+    pub timestamp: Option<u32>,
+    /// From `data` for `timer`.
+    pub timer_trigger: Option<TimerTrigger>,
+    /// From `data` for `battery`, `V`.
+    pub battery_level: Option<f64>,
+    pub(crate) index: usize,
+}
+
+impl EventMessage {
+    /// Parse `DataMessage` as `EventMessage` (`event/21`).
+    /// Fields that are not logged, or only contain invalid values,
+    /// are `None`.
+    pub fn new(data_message: &DataMessage) -> Result<Self, FitError> {
+        let global_id = 21_u16; // event
+
+        if data_message.global != global_id {
+            return Err(FitError::UnexpectedMessageType{expected: global_id, got: data_message.global})
+        }
+
+        let mut event = Self {
+            index: data_message.index,
+            ..Self::default()
+        };
+        let mut data16: Option<u16> = None; // field no 2
+
+        for field in data_message.fields.iter() {
+            match field.field_def_no() {
+                0 => event.event = field.value_as().and_then(Event::from_u8),
+                1 => event.event_type = field.value_as().and_then(EventType::from_u8),
+                2 => data16 = field.value_as(),
+                3 => event.data = field.value_as(),
+                4 => event.event_group = field.value_as(),
+                7 => event.score = field.value_as(),
+                8 => event.opponent_score = field.value_as(),
+                9 => event.front_gear_num = field.value_as(),
+                10 => event.front_gear = field.value_as(),
+                11 => event.rear_gear_num = field.value_as(),
+                12 => event.rear_gear = field.value_as(),
+                13 => event.device_index = field.value_as(),
+                21 => event.radar_threat_level_max = field.value_as(),
+                22 => event.radar_threat_count = field.value_as(),
+                253 => event.timestamp = field.value_as(),
+                _ => (),
+            }
+        }
+
+        if event.data.is_none() {
+            event.data = data16.map(u32::from);
+        }
+
+        // Decode `data` as subfields/components would in `Fit::augment()`,
+        // keeping any values logged as separate fields
+        if let Some(data) = event.data {
+            let bytes = data.to_le_bytes();
+            match event.event {
+                Some(Event::Timer) => event.timer_trigger = TimerTrigger::from_u8(bytes[0]),
+                Some(Event::Battery) => event.battery_level = FitMessageType::get(global_id)
+                    .and_then(|mt| mt.field(3))
+                    .and_then(|f| f.subfield(&data_message.fields))
+                    .map(|sub| data as f64 / sub.scale.unwrap_or(1.)),
+                Some(Event::SportPoint) => {
+                    event.score = event.score.or(Some(data as u16));
+                    event.opponent_score = event.opponent_score.or(Some((data >> 16) as u16));
+                },
+                Some(Event::FrontGearChange | Event::RearGearChange) => {
+                    event.rear_gear_num = event.rear_gear_num.or(Some(bytes[0]));
+                    event.rear_gear = event.rear_gear.or(Some(bytes[1]));
+                    event.front_gear_num = event.front_gear_num.or(Some(bytes[2]));
+                    event.front_gear = event.front_gear.or(Some(bytes[3]));
+                },
+                _ => ()
+            }
+        }
+
+        Ok(event)
+    }
+
+    /// Extract all `EventMessage` (`event/21`) from FIT, in logging order.
+    pub fn from_fit(
+        fit: &Fit,
+        range: Option<&Range<usize>>, // slice indeces for session
+    ) -> Result<Vec<Self>, FitError> {
+        let global = 21_u16;
+
+        let range = range.cloned().unwrap_or(0 .. fit.len());
+
+        fit.records[range].par_iter()
+            .filter(|rec| rec.global == global)
+            .map(Self::new)
+            .collect()
+    }
+
+    /// Returns `true` if the timer was started.
+    pub fn is_timer_start(&self) -> bool {
+        self.event == Some(Event::Timer)
+            && self.event_type == Some(EventType::Start)
+    }
+
+    /// Returns `true` if the timer was stopped,
+    /// including `stop_all` and `stop_disable`.
+    pub fn is_timer_stop(&self) -> bool {
+        self.event == Some(Event::Timer)
+            && matches!(
+                self.event_type,
+                Some(EventType::Stop | EventType::StopAll | EventType::StopDisable | EventType::StopDisableAll)
+            )
+    }
+}
@@ -11,6 +11,7 @@ use crate::Fit;
 /// Field Description Message, global id 206.
 /// Describes the structure for custom data.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FieldDescriptionMessage {
     /// Field def no: 0, Index of the developer that this message maps to
     pub developer_data_index: u8,
@@ -7,6 +7,7 @@ use std::ops::Range;
 
 use crate::{fit::DataMessage, Fit, FitError};
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FileId {
     // Field definition number 3
     pub serial_number: u32, // SCL: 1 OFF: 0 UNIT: N/A Uint32z([3936074477])
@@ -16,10 +17,10 @@ pub struct FileId {
     // UNKNOWN_FIELD   // SCL: 1 OFF: 0 UNIT: N/A Uint32([4294967295])
     // Field definition number 1
     pub manufacturer: u16, // SCL: 1 OFF: 0 UNIT: N/A Uint16([1])
-    // Field definition number 2
-    pub product: u16,     // SCL: 1 OFF: 0 UNIT: N/A Uint16([65535])
-    // Field definition number 5
-    pub number: u16,      // SCL: 1 OFF: 0 UNIT: N/A Uint16([65535])
+    // Field definition number 2, invalid/not set for VIRB
+    pub product: Option<u16>,     // SCL: 1 OFF: 0 UNIT: N/A Uint16([65535])
+    // Field definition number 5, invalid/not set for VIRB
+    pub number: Option<u16>,      // SCL: 1 OFF: 0 UNIT: N/A Uint16([65535])
     // Field definition number 0
     pub r#type: u8,       // SCL: 1 OFF: 0 UNIT: N/A Enum([4])
     pub(crate) index: usize,
@@ -67,10 +68,8 @@ impl FileId {
                 .ok_or_else(|| FitError::ErrorAssigningField { global: global_id, field_def_no: 4 })?,
             manufacturer: manufacturer
                 .ok_or_else(|| FitError::ErrorAssigningField { global: global_id, field_def_no: 1 })?,
-            product: product
-                .ok_or_else(|| FitError::ErrorAssigningField { global: global_id, field_def_no: 2 })?,
-            number: number
-                .ok_or_else(|| FitError::ErrorAssigningField { global: global_id, field_def_no: 5 })?,
+            product,
+            number,
             r#type: product_type
                 .ok_or_else(|| FitError::ErrorAssigningField { global: global_id, field_def_no: 0 })?,
             index: data_message.index
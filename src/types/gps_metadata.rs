@@ -2,13 +2,14 @@
 //! Other devices may return an error if `gps_metadata` exists,
 //! and may not include coordinates. Look in `20/record` instead if this is the case.
 
-use std::ops::Range;
+use std::{fmt, ops::Range};
 use time::Duration;
 
 use crate::{
     FitError,
     Fit,
-    fit::DataMessage,
+    fit::{units, DataMessage, UnitSystem},
+    profile::FitMessageType,
 };
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 
@@ -18,23 +19,25 @@ use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 /// Note: Garmin VIRB Ultra 30 logs additional data not documented in the FIT SDK, ignored here.
 ///       Run `geoelan check -f FITFILE -g 160 --verbose` to view these
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GpsMetadata {
     /// Field definition number 253, seconds
     pub timestamp: u32,
-    /// Field definition number 0, milliseconds
-    pub timestamp_ms: u16,
+    /// Field definition number 0, milliseconds, `None` if not logged or invalid
+    pub timestamp_ms: Option<u16>,
     /// Field definition number 1
     pub latitude: i32,
     /// Field definition number 2
     pub longitude: i32,
-    /// Field definition number 3
-    pub altitude: u32,
-    /// Field definition number 4
-    pub speed: u32,
-    /// Field definition number 5
-    pub heading: u16,
-    pub utc_timestamp: u32, // id:6
-    pub velocity: Vec<i16>, // id:7 Vec::with_capacity(3), x, y, z velocity values, was [i16;3]
+    /// Field definition number 3, `None` if not logged or invalid
+    pub altitude: Option<u32>,
+    /// Field definition number 4, `None` if not logged or invalid
+    pub speed: Option<u32>,
+    /// Field definition number 5, `None` if not logged or invalid
+    pub heading: Option<u16>,
+    /// Field definition number 6, seconds, `None` if not logged or invalid
+    pub utc_timestamp: Option<u32>,
+    pub velocity: Option<Vec<i16>>, // id:7 Vec::with_capacity(3), x, y, z velocity values, was [i16;3]
                             // pub unknown: [u16;5] // id:8-12 not in Profile.xlsx, exists in definition message
     pub(crate) index: usize
 }
@@ -77,23 +80,17 @@ impl GpsMetadata {
 
         Ok(Self {
             timestamp: timestamp
-                .ok_or_else(|| FitError::ErrorAssigningField{global: global_id, field_def_no: 253})?,
-            utc_timestamp: utc_timestamp
-                .ok_or_else(|| FitError::ErrorAssigningField{global: global_id, field_def_no: 6})?,
-            timestamp_ms: timestamp_ms
-                .ok_or_else(|| FitError::ErrorAssigningField{global: global_id, field_def_no: 0})?,
+                .ok_or_else(|| data_message.field_error(253))?,
             latitude: latitude
-                .ok_or_else(|| FitError::ErrorAssigningField{global: global_id, field_def_no: 1})?,
+                .ok_or_else(|| data_message.field_error(1))?,
             longitude: longitude
-                .ok_or_else(|| FitError::ErrorAssigningField{global: global_id, field_def_no: 2})?,
-            altitude: altitude
-                .ok_or_else(|| FitError::ErrorAssigningField{global: global_id, field_def_no: 3})?,
-            speed: speed
-                .ok_or_else(|| FitError::ErrorAssigningField{global: global_id, field_def_no: 4})?,
-            velocity: velocity
-                .ok_or_else(|| FitError::ErrorAssigningField{global: global_id, field_def_no: 7})?,
-            heading: heading
-                .ok_or_else(|| FitError::ErrorAssigningField{global: global_id, field_def_no: 5})?,
+                .ok_or_else(|| data_message.field_error(2))?,
+            utc_timestamp,
+            timestamp_ms,
+            altitude,
+            speed,
+            velocity,
+            heading,
             index: data_message.index
         })
     }
@@ -103,6 +100,11 @@ impl GpsMetadata {
     /// Error handling is for determining whether a required field 
     /// was present in the input data.
     /// 
+    /// Messages where timestamp, latitude, or longitude only contain
+    /// invalid values, e.g. coordinates logged before satellite lock,
+    /// are skipped. Milliseconds, altitude, speed, heading,
+    /// UTC timestamp, and velocity are `None` if invalid.
+    ///
     /// If FIT contains no gps_metadata/160,
     /// an empty vec will be returned with no errors
     pub fn from_fit(
@@ -116,44 +118,48 @@ impl GpsMetadata {
         fit.records[range].par_iter()
             .filter(|rec| rec.global == global)
             .map(Self::new)
+            // Skip messages with invalid coordinates, e.g. before satellite lock
+            .filter(|gps| !matches!(gps, Err(FitError::InvalidFieldValue{field_def_no: 253 | 1 | 2, ..})))
             .collect()
     }
 
-    /// Convert gps_metadata basetype values to decimal degrees etc
+    /// Convert gps_metadata basetype values to decimal degrees etc,
+    /// using scale, offset and units in the FIT profile.
     pub fn to_point(&self) -> FitPoint {
-        let semi2deg = 180.0 / 2.0_f64.powi(31);
+        let [latitude, longitude, altitude] = self.to_decimal();
         FitPoint {
-            latitude: (self.latitude as f64) * semi2deg,
-            longitude: (self.longitude as f64) * semi2deg,
-            altitude: (self.altitude as f64 / 5.0) - 500.0,
-            speed2d: self.speed as f64 / 1000.0,
-            speed3d: (
-                self.velocity[0].pow(2) as f64 +
-                self.velocity[1].pow(2) as f64 +
-                self.velocity[2].pow(2) as f64
-            ).sqrt() / 100.0,
-            heading: self.heading as f64 / 100.0, // scale 100
+            latitude,
+            longitude,
+            altitude,
+            speed2d: self.speed.map(|s| FitMessageType::to_physical(160, 4, s as f64)).unwrap_or(0.0),
+            speed3d: self.velocity.iter()
+                .flatten()
+                .map(|v| FitMessageType::to_physical(160, 7, *v as f64).powi(2))
+                .sum::<f64>()
+                .sqrt(),
+            heading: self.heading.map(|h| FitMessageType::to_physical(160, 5, h as f64)).unwrap_or(0.0),
             time: {
                 Duration::seconds(self.timestamp as i64)
-                    + Duration::milliseconds(self.timestamp_ms as i64)
+                    + Duration::milliseconds(self.timestamp_ms.unwrap_or(0) as i64)
             },
             text: None,
         }
     }
 
     /// Returns `[latitude, longitude, altitude]` as decimal degrees and meters.
+    /// Altitude is `0.0` if not logged or invalid.
     pub fn to_decimal(&self) -> [f64; 3] {
-        let semi2deg = 180.0 / 2.0_f64.powi(31);
         [
-            (self.latitude as f64) * semi2deg,
-            (self.longitude as f64) * semi2deg,
-            (self.altitude as f64 / 5.0) - 500.0 // FIT SDK: scale 5, offset 500
+            FitMessageType::to_physical(160, 1, self.latitude as f64),
+            FitMessageType::to_physical(160, 2, self.longitude as f64),
+            self.altitude.map(|alt| FitMessageType::to_physical(160, 3, alt as f64)).unwrap_or(0.0),
         ]
     }
 }
 
 /// For converting gps_metadata/161 to decimal values.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FitPoint {
     pub latitude: f64,  // id:1
     pub longitude: f64, // id:2
@@ -165,3 +171,45 @@ pub struct FitPoint {
     pub time: Duration,
     pub text: Option<String>,
 }
+
+impl FitPoint {
+    /// Returns a wrapper for displaying the point in `system`,
+    /// e.g. altitude in feet and speed in mph for `UnitSystem::Imperial`.
+    pub fn display(&self, system: UnitSystem) -> FitPointDisplay<'_> {
+        FitPointDisplay {
+            point: self,
+            system,
+        }
+    }
+}
+
+/// Displays a `FitPoint` converted to a `UnitSystem`,
+/// see `FitPoint::display()`.
+#[derive(Debug, Clone, Copy)]
+pub struct FitPointDisplay<'a> {
+    point: &'a FitPoint,
+    system: UnitSystem,
+}
+
+impl fmt::Display for FitPointDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (altitude, altitude_units) = self.system.convert(self.point.altitude, "m");
+        let (speed2d, speed_units) = self.system.convert(self.point.speed2d, "m/s");
+        let (speed3d, _) = self.system.convert(self.point.speed3d, "m/s");
+        write!(
+            f,
+            "lat: {:.6}, lon: {:.6}, alt: {} {altitude_units}, speed 2D: {} {speed_units}, speed 3D: {} {speed_units}, heading: {} degrees, time: {}",
+            self.point.latitude,
+            self.point.longitude,
+            units::round(altitude),
+            units::round(speed2d),
+            units::round(speed3d),
+            units::round(self.point.heading),
+            self.point.time,
+        )?;
+        match &self.point.text {
+            Some(text) => write!(f, ", {text}"),
+            None => Ok(())
+        }
+    }
+}
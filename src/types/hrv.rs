@@ -0,0 +1,56 @@
+//! FIT `hrv` message (global ID 78).
+//!
+//! Heart rate variability, i.e. RR-intervals (time between heart beats),
+//! logged in batches as an array (`0/time`).
+
+use std::ops::Range;
+
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+
+use crate::{fit::DataMessage, Fit, FitError};
+
+/// Heart rate variability, global ID 78.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Hrv {
+    /// Field def no: 0, RR-intervals in seconds, in logging order.
+    /// Invalid values, i.e. padding, are excluded.
+    pub time: Vec<f64>,
+    pub(crate) index: usize,
+}
+
+impl Hrv {
+    /// Parse `DataMessage` as `Hrv` (`hrv/78`).
+    pub fn new(data_message: &DataMessage) -> Result<Self, FitError> {
+        let global_id = 78_u16; // hrv
+
+        if data_message.global != global_id {
+            return Err(FitError::UnexpectedMessageType{expected: global_id, got: data_message.global})
+        }
+
+        let time = data_message.fields.iter()
+            .find(|f| f.field_def_no() == 0)
+            .map(|f| f.profile_values_f64(global_id).into_iter().flatten().collect())
+            .unwrap_or_default();
+
+        Ok(Self {
+            time,
+            index: data_message.index
+        })
+    }
+
+    /// Extract all `Hrv` (`hrv/78`) from FIT, in logging order.
+    pub fn from_fit(
+        fit: &Fit,
+        range: Option<&Range<usize>>, // slice indeces for session
+    ) -> Result<Vec<Self>, FitError> {
+        let global = 78_u16;
+
+        let range = range.cloned().unwrap_or(0 .. fit.len());
+
+        fit.records[range].par_iter()
+            .filter(|rec| rec.global == global)
+            .map(Self::new)
+            .collect()
+    }
+}
@@ -0,0 +1,389 @@
+//! FIT `lap` message (global ID 19).
+//!
+//! Summary of a lap, such as start time (`2/start_time`),
+//! and totals and averages for distance, speed, heart rate etc.
+
+use std::ops::Range;
+
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+
+use crate::{fit::DataMessage, Fit, FitError};
+use crate::{Event, EventType, Sport, SubSport};
+
+/// Lap summary, global ID 19.
+/// Values are physical values, i.e. with scale and offset
+/// from the FIT profile applied, and `semicircles`
+/// converted to decimal degrees.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Lap {
+    /// Field def no: 0
+    pub event: Option<Event>,
+    /// Field def no: 1
+    pub event_type: Option<EventType>,
+    /// Field def no: 2, FIT timestamp in seconds
+    pub start_time: Option<u32>,
+    /// Field def no: 3, `degrees`
+    pub start_position_lat: Option<f64>,
+    /// Field def no: 4, `degrees`
+    pub start_position_long: Option<f64>,
+    /// Field def no: 5, `degrees`
+    pub end_position_lat: Option<f64>,
+    /// Field def no: 6, `degrees`
+    pub end_position_long: Option<f64>,
+    /// Field def no: 7, `s`
+    pub total_elapsed_time: Option<f64>,
+    /// Field def no: 8, `s`
+    pub total_timer_time: Option<f64>,
+    /// Field def no: 9, `m`
+    pub total_distance: Option<f64>,
+    /// Field def no: 10, `cycles`
+    pub total_cycles: Option<f64>,
+    /// Field def no: 11, `kcal`
+    pub total_calories: Option<f64>,
+    /// Field def no: 12, `kcal`
+    pub total_fat_calories: Option<f64>,
+    /// Field def no: 13, `m/s`
+    pub avg_speed: Option<f64>,
+    /// Field def no: 14, `m/s`
+    pub max_speed: Option<f64>,
+    /// Field def no: 15, `bpm`
+    pub avg_heart_rate: Option<f64>,
+    /// Field def no: 16, `bpm`
+    pub max_heart_rate: Option<f64>,
+    /// Field def no: 17, `rpm`
+    pub avg_cadence: Option<f64>,
+    /// Field def no: 18, `rpm`
+    pub max_cadence: Option<f64>,
+    /// Field def no: 19, `watts`
+    pub avg_power: Option<f64>,
+    /// Field def no: 20, `watts`
+    pub max_power: Option<f64>,
+    /// Field def no: 21, `m`
+    pub total_ascent: Option<f64>,
+    /// Field def no: 22, `m`
+    pub total_descent: Option<f64>,
+    /// Field def no: 23
+    pub intensity: Option<u8>,
+    /// Field def no: 24
+    pub lap_trigger: Option<u8>,
+    /// Field def no: 25
+    pub sport: Option<Sport>,
+    /// Field def no: 26
+    pub event_group: Option<u8>,
+    /// Field def no: 32, `lengths`
+    pub num_lengths: Option<f64>,
+    /// Field def no: 33, `watts`
+    pub normalized_power: Option<f64>,
+    /// Field def no: 34
+    pub left_right_balance: Option<u16>,
+    /// Field def no: 35
+    pub first_length_index: Option<u16>,
+    /// Field def no: 37, `m`
+    pub avg_stroke_distance: Option<f64>,
+    /// Field def no: 38
+    pub swim_stroke: Option<u8>,
+    /// Field def no: 39
+    pub sub_sport: Option<SubSport>,
+    /// Field def no: 40, `lengths`
+    pub num_active_lengths: Option<f64>,
+    /// Field def no: 41, `J`
+    pub total_work: Option<f64>,
+    /// Field def no: 42, `m`
+    pub avg_altitude: Option<f64>,
+    /// Field def no: 43, `m`
+    pub max_altitude: Option<f64>,
+    /// Field def no: 44, `m`
+    pub gps_accuracy: Option<f64>,
+    /// Field def no: 45, `%`
+    pub avg_grade: Option<f64>,
+    /// Field def no: 46, `%`
+    pub avg_pos_grade: Option<f64>,
+    /// Field def no: 47, `%`
+    pub avg_neg_grade: Option<f64>,
+    /// Field def no: 48, `%`
+    pub max_pos_grade: Option<f64>,
+    /// Field def no: 49, `%`
+    pub max_neg_grade: Option<f64>,
+    /// Field def no: 50, `C`
+    pub avg_temperature: Option<f64>,
+    /// Field def no: 51, `C`
+    pub max_temperature: Option<f64>,
+    /// Field def no: 52, `s`
+    pub total_moving_time: Option<f64>,
+    /// Field def no: 53, `m/s`
+    pub avg_pos_vertical_speed: Option<f64>,
+    /// Field def no: 54, `m/s`
+    pub avg_neg_vertical_speed: Option<f64>,
+    /// Field def no: 55, `m/s`
+    pub max_pos_vertical_speed: Option<f64>,
+    /// Field def no: 56, `m/s`
+    pub max_neg_vertical_speed: Option<f64>,
+    /// Field def no: 57, `s`, one value per element
+    pub time_in_hr_zone: Vec<Option<f64>>,
+    /// Field def no: 58, `s`, one value per element
+    pub time_in_speed_zone: Vec<Option<f64>>,
+    /// Field def no: 59, `s`, one value per element
+    pub time_in_cadence_zone: Vec<Option<f64>>,
+    /// Field def no: 60, `s`, one value per element
+    pub time_in_power_zone: Vec<Option<f64>>,
+    /// Field def no: 61
+    pub repetition_num: Option<u16>,
+    /// Field def no: 62, `m`
+    pub min_altitude: Option<f64>,
+    /// Field def no: 63, `bpm`
+    pub min_heart_rate: Option<f64>,
+    /// Field def no: 71
+    pub wkt_step_index: Option<u16>,
+    /// Field def no: 74
+    pub opponent_score: Option<u16>,
+    /// Field def no: 75, `counts`, one value per element
+    pub stroke_count: Vec<Option<f64>>,
+    /// Field def no: 76, `counts`, one value per element
+    pub zone_count: Vec<Option<f64>>,
+    /// Field def no: 77, `mm`
+    pub avg_vertical_oscillation: Option<f64>,
+    /// Field def no: 78, `percent`
+    pub avg_stance_time_percent: Option<f64>,
+    /// Field def no: 79, `ms`
+    pub avg_stance_time: Option<f64>,
+    /// Field def no: 80, `rpm`
+    pub avg_fractional_cadence: Option<f64>,
+    /// Field def no: 81, `rpm`
+    pub max_fractional_cadence: Option<f64>,
+    /// Field def no: 82, `cycles`
+    pub total_fractional_cycles: Option<f64>,
+    /// Field def no: 83
+    pub player_score: Option<u16>,
+    /// Field def no: 84, `g/dL`, one value per element
+    pub avg_total_hemoglobin_conc: Vec<Option<f64>>,
+    /// Field def no: 85, `g/dL`, one value per element
+    pub min_total_hemoglobin_conc: Vec<Option<f64>>,
+    /// Field def no: 86, `g/dL`, one value per element
+    pub max_total_hemoglobin_conc: Vec<Option<f64>>,
+    /// Field def no: 87, `%`, one value per element
+    pub avg_saturated_hemoglobin_percent: Vec<Option<f64>>,
+    /// Field def no: 88, `%`, one value per element
+    pub min_saturated_hemoglobin_percent: Vec<Option<f64>>,
+    /// Field def no: 89, `%`, one value per element
+    pub max_saturated_hemoglobin_percent: Vec<Option<f64>>,
+    /// Field def no: 91, `percent`
+    pub avg_left_torque_effectiveness: Option<f64>,
+    /// Field def no: 92, `percent`
+    pub avg_right_torque_effectiveness: Option<f64>,
+    /// Field def no: 93, `percent`
+    pub avg_left_pedal_smoothness: Option<f64>,
+    /// Field def no: 94, `percent`
+    pub avg_right_pedal_smoothness: Option<f64>,
+    /// Field def no: 95, `percent`
+    pub avg_combined_pedal_smoothness: Option<f64>,
+    /// Field def no: 98, `s`
+    pub time_standing: Option<f64>,
+    /// Field def no: 99
+    pub stand_count: Option<u16>,
+    /// Field def no: 100, `mm`
+    pub avg_left_pco: Option<f64>,
+    /// Field def no: 101, `mm`
+    pub avg_right_pco: Option<f64>,
+    /// Field def no: 106, `watts`, one value per element
+    pub avg_power_position: Vec<Option<f64>>,
+    /// Field def no: 107, `watts`, one value per element
+    pub max_power_position: Vec<Option<f64>>,
+    /// Field def no: 108, `rpm`, one value per element
+    pub avg_cadence_position: Vec<Option<f64>>,
+    /// Field def no: 109, `rpm`, one value per element
+    pub max_cadence_position: Vec<Option<f64>>,
+    /// Field def no: 110, `m/s`
+    pub enhanced_avg_speed: Option<f64>,
+    /// Field def no: 111, `m/s`
+    pub enhanced_max_speed: Option<f64>,
+    /// Field def no: 112, `m`
+    pub enhanced_avg_altitude: Option<f64>,
+    /// Field def no: 113, `m`
+    pub enhanced_min_altitude: Option<f64>,
+    /// Field def no: 114, `m`
+    pub enhanced_max_altitude: Option<f64>,
+    /// Field def no: 115, `watts`
+    pub avg_lev_motor_power: Option<f64>,
+    /// Field def no: 116, `watts`
+    pub max_lev_motor_power: Option<f64>,
+    /// Field def no: 117, `percent`
+    pub lev_battery_consumption: Option<f64>,
+    /// Field def no: 118, `percent`
+    pub avg_vertical_ratio: Option<f64>,
+    /// Field def no: 119, `percent`
+    pub avg_stance_time_balance: Option<f64>,
+    /// Field def no: 120, `mm`
+    pub avg_step_length: Option<f64>,
+    /// Field def no: 121, `m/s`
+    pub avg_vam: Option<f64>,
+    /// Field def no: 149, `kGrit`
+    pub total_grit: Option<f64>,
+    /// Field def no: 150, `Flow`
+    pub total_flow: Option<f64>,
+    /// Field def no: 151
+    pub jump_count: Option<u16>,
+    /// Field def no: 153, `kGrit`
+    pub avg_grit: Option<f64>,
+    /// Field def no: 154, `Flow`
+    pub avg_flow: Option<f64>,
+    /// Field def no: 156, `m`
+    pub total_fractional_ascent: Option<f64>,
+    /// Field def no: 157, `m`
+    pub total_fractional_descent: Option<f64>,
+    /// Field def no: 253, FIT timestamp in seconds
+    pub timestamp: Option<u32>,
+    /// Field def no: 254
+    pub message_index: Option<u16>,
+    pub(crate) index: usize,
+}
+
+impl Lap {
+    /// Parse `DataMessage` as `Lap` (`lap/19`).
+    /// Fields that are not logged, or only contain invalid values,
+    /// are `None`.
+    pub fn new(data_message: &DataMessage) -> Result<Self, FitError> {
+        let global_id = 19_u16; // lap
+
+        if data_message.global != global_id {
+            return Err(FitError::UnexpectedMessageType{expected: global_id, got: data_message.global})
+        }
+
+        let mut lap = Self {
+            index: data_message.index,
+            ..Self::default()
+        };
+
+        for field in data_message.fields.iter() {
+            match field.field_def_no() {
+                0 => lap.event = field.value_as().and_then(Event::from_u8),
+                1 => lap.event_type = field.value_as().and_then(EventType::from_u8),
+                2 => lap.start_time = field.value_as(),
+                3 => lap.start_position_lat = field.profile_value_f64(global_id),
+                4 => lap.start_position_long = field.profile_value_f64(global_id),
+                5 => lap.end_position_lat = field.profile_value_f64(global_id),
+                6 => lap.end_position_long = field.profile_value_f64(global_id),
+                7 => lap.total_elapsed_time = field.profile_value_f64(global_id),
+                8 => lap.total_timer_time = field.profile_value_f64(global_id),
+                9 => lap.total_distance = field.profile_value_f64(global_id),
+                10 => lap.total_cycles = field.profile_value_f64(global_id),
+                11 => lap.total_calories = field.profile_value_f64(global_id),
+                12 => lap.total_fat_calories = field.profile_value_f64(global_id),
+                13 => lap.avg_speed = field.profile_value_f64(global_id),
+                14 => lap.max_speed = field.profile_value_f64(global_id),
+                15 => lap.avg_heart_rate = field.profile_value_f64(global_id),
+                16 => lap.max_heart_rate = field.profile_value_f64(global_id),
+                17 => lap.avg_cadence = field.profile_value_f64(global_id),
+                18 => lap.max_cadence = field.profile_value_f64(global_id),
+                19 => lap.avg_power = field.profile_value_f64(global_id),
+                20 => lap.max_power = field.profile_value_f64(global_id),
+                21 => lap.total_ascent = field.profile_value_f64(global_id),
+                22 => lap.total_descent = field.profile_value_f64(global_id),
+                23 => lap.intensity = field.value_as(),
+                24 => lap.lap_trigger = field.value_as(),
+                25 => lap.sport = field.value_as().and_then(Sport::from_u8),
+                26 => lap.event_group = field.value_as(),
+                32 => lap.num_lengths = field.profile_value_f64(global_id),
+                33 => lap.normalized_power = field.profile_value_f64(global_id),
+                34 => lap.left_right_balance = field.value_as(),
+                35 => lap.first_length_index = field.value_as(),
+                37 => lap.avg_stroke_distance = field.profile_value_f64(global_id),
+                38 => lap.swim_stroke = field.value_as(),
+                39 => lap.sub_sport = field.value_as().and_then(SubSport::from_u8),
+                40 => lap.num_active_lengths = field.profile_value_f64(global_id),
+                41 => lap.total_work = field.profile_value_f64(global_id),
+                42 => lap.avg_altitude = field.profile_value_f64(global_id),
+                43 => lap.max_altitude = field.profile_value_f64(global_id),
+                44 => lap.gps_accuracy = field.profile_value_f64(global_id),
+                45 => lap.avg_grade = field.profile_value_f64(global_id),
+                46 => lap.avg_pos_grade = field.profile_value_f64(global_id),
+                47 => lap.avg_neg_grade = field.profile_value_f64(global_id),
+                48 => lap.max_pos_grade = field.profile_value_f64(global_id),
+                49 => lap.max_neg_grade = field.profile_value_f64(global_id),
+                50 => lap.avg_temperature = field.profile_value_f64(global_id),
+                51 => lap.max_temperature = field.profile_value_f64(global_id),
+                52 => lap.total_moving_time = field.profile_value_f64(global_id),
+                53 => lap.avg_pos_vertical_speed = field.profile_value_f64(global_id),
+                54 => lap.avg_neg_vertical_speed = field.profile_value_f64(global_id),
+                55 => lap.max_pos_vertical_speed = field.profile_value_f64(global_id),
+                56 => lap.max_neg_vertical_speed = field.profile_value_f64(global_id),
+                57 => lap.time_in_hr_zone = field.profile_values_f64(global_id),
+                58 => lap.time_in_speed_zone = field.profile_values_f64(global_id),
+                59 => lap.time_in_cadence_zone = field.profile_values_f64(global_id),
+                60 => lap.time_in_power_zone = field.profile_values_f64(global_id),
+                61 => lap.repetition_num = field.value_as(),
+                62 => lap.min_altitude = field.profile_value_f64(global_id),
+                63 => lap.min_heart_rate = field.profile_value_f64(global_id),
+                71 => lap.wkt_step_index = field.value_as(),
+                74 => lap.opponent_score = field.value_as(),
+                75 => lap.stroke_count = field.profile_values_f64(global_id),
+                76 => lap.zone_count = field.profile_values_f64(global_id),
+                77 => lap.avg_vertical_oscillation = field.profile_value_f64(global_id),
+                78 => lap.avg_stance_time_percent = field.profile_value_f64(global_id),
+                79 => lap.avg_stance_time = field.profile_value_f64(global_id),
+                80 => lap.avg_fractional_cadence = field.profile_value_f64(global_id),
+                81 => lap.max_fractional_cadence = field.profile_value_f64(global_id),
+                82 => lap.total_fractional_cycles = field.profile_value_f64(global_id),
+                83 => lap.player_score = field.value_as(),
+                84 => lap.avg_total_hemoglobin_conc = field.profile_values_f64(global_id),
+                85 => lap.min_total_hemoglobin_conc = field.profile_values_f64(global_id),
+                86 => lap.max_total_hemoglobin_conc = field.profile_values_f64(global_id),
+                87 => lap.avg_saturated_hemoglobin_percent = field.profile_values_f64(global_id),
+                88 => lap.min_saturated_hemoglobin_percent = field.profile_values_f64(global_id),
+                89 => lap.max_saturated_hemoglobin_percent = field.profile_values_f64(global_id),
+                91 => lap.avg_left_torque_effectiveness = field.profile_value_f64(global_id),
+                92 => lap.avg_right_torque_effectiveness = field.profile_value_f64(global_id),
+                93 => lap.avg_left_pedal_smoothness = field.profile_value_f64(global_id),
+                94 => lap.avg_right_pedal_smoothness = field.profile_value_f64(global_id),
+                95 => lap.avg_combined_pedal_smoothness = field.profile_value_f64(global_id),
+                98 => lap.time_standing = field.profile_value_f64(global_id),
+                99 => lap.stand_count = field.value_as(),
+                100 => lap.avg_left_pco = field.profile_value_f64(global_id),
+                101 => lap.avg_right_pco = field.profile_value_f64(global_id),
+                106 => lap.avg_power_position = field.profile_values_f64(global_id),
+                107 => lap.max_power_position = field.profile_values_f64(global_id),
+                108 => lap.avg_cadence_position = field.profile_values_f64(global_id),
+                109 => lap.max_cadence_position = field.profile_values_f64(global_id),
+                110 => lap.enhanced_avg_speed = field.profile_value_f64(global_id),
+                111 => lap.enhanced_max_speed = field.profile_value_f64(global_id),
+                112 => lap.enhanced_avg_altitude = field.profile_value_f64(global_id),
+                113 => lap.enhanced_min_altitude = field.profile_value_f64(global_id),
+                114 => lap.enhanced_max_altitude = field.profile_value_f64(global_id),
+                115 => lap.avg_lev_motor_power = field.profile_value_f64(global_id),
+                116 => lap.max_lev_motor_power = field.profile_value_f64(global_id),
+                117 => lap.lev_battery_consumption = field.profile_value_f64(global_id),
+                118 => lap.avg_vertical_ratio = field.profile_value_f64(global_id),
+                119 => lap.avg_stance_time_balance = field.profile_value_f64(global_id),
+                120 => lap.avg_step_length = field.profile_value_f64(global_id),
+                121 => lap.avg_vam = field.profile_value_f64(global_id),
+                149 => lap.total_grit = field.profile_value_f64(global_id),
+                150 => lap.total_flow = field.profile_value_f64(global_id),
+                151 => lap.jump_count = field.value_as(),
+                153 => lap.avg_grit = field.profile_value_f64(global_id),
+                154 => lap.avg_flow = field.profile_value_f64(global_id),
+                156 => lap.total_fractional_ascent = field.profile_value_f64(global_id),
+                157 => lap.total_fractional_descent = field.profile_value_f64(global_id),
+                253 => lap.timestamp = field.value_as(),
+                254 => lap.message_index = field.value_as(),
+                _ => (),
+            }
+        }
+
+        Ok(lap)
+    }
+
+    /// Extract all `Lap` (`lap/19`) from FIT, in logging order.
+    pub fn from_fit(
+        fit: &Fit,
+        range: Option<&Range<usize>>, // slice indeces for session
+    ) -> Result<Vec<Self>, FitError> {
+        let global = 19_u16;
+
+        let range = range.cloned().unwrap_or(0 .. fit.len());
+
+        fit.records[range].par_iter()
+            .filter(|rec| rec.global == global)
+            .map(Self::new)
+            .collect()
+    }
+}
@@ -1,22 +1,38 @@
 //! A few conversions from raw data into easier to use forms and (sometimes) units, such as GPS and sensor data.
 //! Please note that automatic conversion is only covered for the message types covered here.
 
+pub mod activity;
+pub mod developer_data_id;
+pub mod device_info;
+pub mod event;
 pub mod field_description;
 pub mod file_id;
 pub mod gps_metadata;
+pub mod hrv;
+pub mod lap;
 pub mod record_virb;
+pub mod session;
 pub mod timestamp_correlation;
+pub mod time_model;
 pub mod camera_event;
 pub mod sensor_data;
 
+pub use activity::Activity;
 pub use camera_event::CameraEvent;
+pub use developer_data_id::DeveloperDataId;
+pub use device_info::DeviceInfo;
+pub use event::EventMessage;
 pub use field_description::FieldDescriptionMessage;
 pub use file_id::FileId;
-pub use gps_metadata::{GpsMetadata, FitPoint};
+pub use gps_metadata::{GpsMetadata, FitPoint, FitPointDisplay};
+pub use hrv::Hrv;
+pub use lap::Lap;
 pub use record_virb::Record;
+pub use session::Session;
 pub use sensor_data::{
     SensorCalibration,
     SensorData,
     SensorType
 };
-pub use timestamp_correlation::TimestampCorrelation;
\ No newline at end of file
+pub use timestamp_correlation::TimestampCorrelation;
+pub use time_model::{TimeDrift, TimeModel, TimePoint, TimePointSource};
\ No newline at end of file
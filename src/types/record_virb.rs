@@ -8,6 +8,7 @@ use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 
 use crate::{
     fit::DataMessage,
+    profile::FitMessageType,
     FitError,
     Fit
 };
@@ -21,12 +22,13 @@ use super::gps_metadata::FitPoint;
 /// FIT-files from both Garmin VIRB Ultra 30,
 /// and (most?) watches.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Record {
     pub timestamp: u32, // field 253, timestamp, SCL: 1 OFF: 0 UNIT:s UINT32([6326])
     pub latitude: i32, // field 0, position_lat, SCL: 1 OFF: 0 UNIT:semicircles SINT32([66924028])
     pub longitude: i32, // field 1, position_long, SCL: 1 OFF: 0 UNIT:semicircles SINT32([1210417996])
-    pub distance: u32, // field 5, distance, SCL: 100 OFF: 0 UNIT:m UINT32([411482])
-    pub speed: u32, // field 73, enhanced_speed, SCL: 1000 OFF: 0 UNIT:m s UINT32([569])
+    pub distance: Option<u32>, // field 5, distance, SCL: 100 OFF: 0 UNIT:m UINT32([411482])
+    pub speed: Option<u32>, // field 73, enhanced_speed, SCL: 1000 OFF: 0 UNIT:m s UINT32([569])
     pub altitude: Option<u32>, // field 78, enhanced_altitude, SCL: 5 OFF: 500 UNIT:m UINT32([5723])
     pub gps_accuracy: Option<u8>, // field 31, gps_accuracy, no SCL or OFF, UNIT:M UINT8([2])
     pub(crate) index: usize
@@ -84,16 +86,14 @@ impl Record {
 
         Ok(Self {
             timestamp: timestamp
-                .ok_or_else(|| FitError::ErrorAssigningField{global: global_id, field_def_no: 253})?,
+                .ok_or_else(|| data_message.field_error(253))?,
             latitude: latitude
-                .ok_or_else(|| FitError::ErrorAssigningField{global: global_id, field_def_no: 0})?,
+                .ok_or_else(|| data_message.field_error(0))?,
             longitude: longitude
-                .ok_or_else(|| FitError::ErrorAssigningField{global: global_id, field_def_no: 1})?,
+                .ok_or_else(|| data_message.field_error(1))?,
             altitude,
-            speed: speed
-                .ok_or_else(|| FitError::ErrorAssigningField{global: global_id, field_def_no: 6})?, // or 73...
-            distance: distance
-                .ok_or_else(|| FitError::ErrorAssigningField{global: global_id, field_def_no: 5})?,
+            speed,
+            distance,
             gps_accuracy,
             index: data_message.index
         })
@@ -108,10 +108,12 @@ impl Record {
     /// where required fields could be assigned are returned.
     /// 
     /// Error handling is for determining whether a required field
-    /// could be assigned or not, i.e. whether the value was present in the input data.
+    /// (timestamp, latitude, longitude) could be assigned or not,
+    /// i.e. whether the value was present in the input data.
     /// For e.g. watches that have not yet sync:ed with a GPS satellite,
-    /// this function will return an error, since required fields can not yet be assigned.
-    /// Set `no_fail = true` if this is unwanted.
+    /// coordinates only contain invalid values, which returns
+    /// `FitError::InvalidFieldValue` unless `no_fail` is set.
+    /// Distance, speed, and altitude are `None` if invalid.
     pub fn from_fit(
         fit: &Fit,
         range: Option<&Range<usize>>, // slice indeces for session
@@ -137,14 +139,14 @@ impl Record {
         }
     }
 
-    /// Convert `Record` geo-location to decimal degrees.
+    /// Convert `Record` geo-location to decimal degrees,
+    /// using scale, offset and units in the FIT profile.
     pub fn to_point(&self) -> FitPoint {
-        let semi2deg = 180.0 / 2.0_f64.powi(31);
         FitPoint {
-            latitude: (self.latitude as f64) * semi2deg,
-            longitude: (self.longitude as f64) * semi2deg,
-            altitude: self.altitude.map(|alt| (alt as f64 / 5.0) - 500.0).unwrap_or(0.0),
-            speed2d: self.speed as f64 / 1000.0,
+            latitude: FitMessageType::to_physical(20, 0, self.latitude as f64),
+            longitude: FitMessageType::to_physical(20, 1, self.longitude as f64),
+            altitude: self.altitude.map(|alt| FitMessageType::to_physical(20, 78, alt as f64)).unwrap_or(0.0),
+            speed2d: self.speed.map(|s| FitMessageType::to_physical(20, 73, s as f64)).unwrap_or(0.0),
             speed3d: 0.0,
             heading: 0.0, // not supported for record/20. self.heading as f64 / 100.0, // scale 100
             time: Duration::seconds(self.timestamp as i64),
@@ -16,6 +16,7 @@ use super::SensorType;
 /// Gyroscope/164 = sensor type 1
 /// Magnetometer/208 = sensor type 2 (compass)
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SensorCalibration {
     pub timestamp: u32,               // id:253, seconds
     pub sensor_type: u8,              // id:0, enum
@@ -36,6 +36,7 @@ use super::{SensorType, SensorCalibration};
 /// - `accelerometer_data`, id = 165
 /// - `magnetometer_data`, id = 208
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SensorData {
     pub sensor_type: SensorType,
     /// timestamp value, id:253, seconds
@@ -114,14 +115,14 @@ impl SensorData {
         Ok(Self {
             sensor_type: sensor_type.to_owned(),
             timestamp: timestamp
-                .ok_or_else(|| FitError::ErrorAssigningField{global: global_id, field_def_no: 253})?,
+                .ok_or_else(|| data_message.field_error(253))?,
             timestamp_ms: timestamp_ms
-                .ok_or_else(|| FitError::ErrorAssigningField{global: global_id, field_def_no: 0})?,
+                .ok_or_else(|| data_message.field_error(0))?,
             sample_time_offset: sample_time_offset
-                .ok_or_else(|| FitError::ErrorAssigningField{global: global_id, field_def_no: 1})?,
-            x: x.ok_or_else(|| FitError::ErrorAssigningField{global: global_id, field_def_no: 2})?,
-            y: y.ok_or_else(|| FitError::ErrorAssigningField{global: global_id, field_def_no: 3})?,
-            z: z.ok_or_else(|| FitError::ErrorAssigningField{global: global_id, field_def_no: 4})?,
+                .ok_or_else(|| data_message.field_error(1))?,
+            x: x.ok_or_else(|| data_message.field_error(2))?,
+            y: y.ok_or_else(|| data_message.field_error(3))?,
+            z: z.ok_or_else(|| data_message.field_error(4))?,
             // calibration done post-extraction
             calibrated_x: Vec::new(),
             calibrated_y: Vec::new(),
@@ -135,6 +136,9 @@ impl SensorData {
     /// - SensorType::Gyroscope = 164,
     /// - SensorType::Accelerometer = 165,
     /// - SensorType::Magnetometer = 208,
+    ///
+    /// Messages where the timestamp, milliseconds, sample time offsets,
+    /// or samples only contain invalid values are skipped.
     pub fn from_fit_uncalibrated(
         fit: &Fit,
         range: Option<&Range<usize>>, // slice indeces for session
@@ -147,6 +151,8 @@ impl SensorData {
         fit.records[range].par_iter()
             .filter(|rec| rec.global == global)
             .map(|rec| SensorData::new(rec, sensor_type))
+            // Skip messages with invalid timestamps or samples
+            .filter(|data| !matches!(data, Err(FitError::InvalidFieldValue{..})))
             .collect()
     }
 
@@ -24,6 +24,7 @@
 
 /// The available 1D, and 3D sensor types as specified in FIT SDK.
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SensorType {
     /// 3D sensor. FIT global ID 165, sensor type enum 0.
     /// Calibration `three_d_calibration`/167
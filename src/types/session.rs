@@ -0,0 +1,434 @@
+//! FIT `session` message (global ID 18).
+//!
+//! Summary of a session, i.e. a single sport,
+//! such as start time (`2/start_time`), sport (`5/sport`),
+//! and totals and averages for distance, speed, heart rate etc.
+//! Multisport activities log one session per sport.
+
+use std::ops::Range;
+
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+
+use crate::{fit::DataMessage, Fit, FitError};
+use crate::{Event, EventType, Sport, SubSport};
+
+/// Session summary, global ID 18.
+/// Values are physical values, i.e. with scale and offset
+/// from the FIT profile applied, and `semicircles`
+/// converted to decimal degrees.
+/// Not to be confused with VIRB recording sessions, see `FitSession`.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Session {
+    /// Field def no: 0
+    pub event: Option<Event>,
+    /// Field def no: 1
+    pub event_type: Option<EventType>,
+    /// Field def no: 2, FIT timestamp in seconds
+    pub start_time: Option<u32>,
+    /// Field def no: 3, `degrees`
+    pub start_position_lat: Option<f64>,
+    /// Field def no: 4, `degrees`
+    pub start_position_long: Option<f64>,
+    /// Field def no: 5
+    pub sport: Option<Sport>,
+    /// Field def no: 6
+    pub sub_sport: Option<SubSport>,
+    /// Field def no: 7, `s`
+    pub total_elapsed_time: Option<f64>,
+    /// Field def no: 8, `s`
+    pub total_timer_time: Option<f64>,
+    /// Field def no: 9, `m`
+    pub total_distance: Option<f64>,
+    /// Field def no: 10, `cycles`
+    pub total_cycles: Option<f64>,
+    /// Field def no: 11, `kcal`
+    pub total_calories: Option<f64>,
+    /// Field def no: 13, `kcal`
+    pub total_fat_calories: Option<f64>,
+    /// Field def no: 14, `m/s`
+    pub avg_speed: Option<f64>,
+    /// Field def no: 15, `m/s`
+    pub max_speed: Option<f64>,
+    /// Field def no: 16, `bpm`
+    pub avg_heart_rate: Option<f64>,
+    /// Field def no: 17, `bpm`
+    pub max_heart_rate: Option<f64>,
+    /// Field def no: 18, `rpm`
+    pub avg_cadence: Option<f64>,
+    /// Field def no: 19, `rpm`
+    pub max_cadence: Option<f64>,
+    /// Field def no: 20, `watts`
+    pub avg_power: Option<f64>,
+    /// Field def no: 21, `watts`
+    pub max_power: Option<f64>,
+    /// Field def no: 22, `m`
+    pub total_ascent: Option<f64>,
+    /// Field def no: 23, `m`
+    pub total_descent: Option<f64>,
+    /// Field def no: 24
+    pub total_training_effect: Option<f64>,
+    /// Field def no: 25
+    pub first_lap_index: Option<u16>,
+    /// Field def no: 26
+    pub num_laps: Option<u16>,
+    /// Field def no: 27
+    pub event_group: Option<u8>,
+    /// Field def no: 28
+    pub trigger: Option<u8>,
+    /// Field def no: 29, `degrees`
+    pub nec_lat: Option<f64>,
+    /// Field def no: 30, `degrees`
+    pub nec_long: Option<f64>,
+    /// Field def no: 31, `degrees`
+    pub swc_lat: Option<f64>,
+    /// Field def no: 32, `degrees`
+    pub swc_long: Option<f64>,
+    /// Field def no: 33, `lengths`
+    pub num_lengths: Option<f64>,
+    /// Field def no: 34, `watts`
+    pub normalized_power: Option<f64>,
+    /// Field def no: 35, `tss`
+    pub training_stress_score: Option<f64>,
+    /// Field def no: 36, `if`
+    pub intensity_factor: Option<f64>,
+    /// Field def no: 37
+    pub left_right_balance: Option<u16>,
+    /// Field def no: 41, `strokes/lap`
+    pub avg_stroke_count: Option<f64>,
+    /// Field def no: 42, `m`
+    pub avg_stroke_distance: Option<f64>,
+    /// Field def no: 43
+    pub swim_stroke: Option<u8>,
+    /// Field def no: 44, `m`
+    pub pool_length: Option<f64>,
+    /// Field def no: 45, `watts`
+    pub threshold_power: Option<f64>,
+    /// Field def no: 46
+    pub pool_length_unit: Option<u8>,
+    /// Field def no: 47, `lengths`
+    pub num_active_lengths: Option<f64>,
+    /// Field def no: 48, `J`
+    pub total_work: Option<f64>,
+    /// Field def no: 49, `m`
+    pub avg_altitude: Option<f64>,
+    /// Field def no: 50, `m`
+    pub max_altitude: Option<f64>,
+    /// Field def no: 51, `m`
+    pub gps_accuracy: Option<f64>,
+    /// Field def no: 52, `%`
+    pub avg_grade: Option<f64>,
+    /// Field def no: 53, `%`
+    pub avg_pos_grade: Option<f64>,
+    /// Field def no: 54, `%`
+    pub avg_neg_grade: Option<f64>,
+    /// Field def no: 55, `%`
+    pub max_pos_grade: Option<f64>,
+    /// Field def no: 56, `%`
+    pub max_neg_grade: Option<f64>,
+    /// Field def no: 57, `C`
+    pub avg_temperature: Option<f64>,
+    /// Field def no: 58, `C`
+    pub max_temperature: Option<f64>,
+    /// Field def no: 59, `s`
+    pub total_moving_time: Option<f64>,
+    /// Field def no: 60, `m/s`
+    pub avg_pos_vertical_speed: Option<f64>,
+    /// Field def no: 61, `m/s`
+    pub avg_neg_vertical_speed: Option<f64>,
+    /// Field def no: 62, `m/s`
+    pub max_pos_vertical_speed: Option<f64>,
+    /// Field def no: 63, `m/s`
+    pub max_neg_vertical_speed: Option<f64>,
+    /// Field def no: 64, `bpm`
+    pub min_heart_rate: Option<f64>,
+    /// Field def no: 65, `s`, one value per element
+    pub time_in_hr_zone: Vec<Option<f64>>,
+    /// Field def no: 66, `s`, one value per element
+    pub time_in_speed_zone: Vec<Option<f64>>,
+    /// Field def no: 67, `s`, one value per element
+    pub time_in_cadence_zone: Vec<Option<f64>>,
+    /// Field def no: 68, `s`, one value per element
+    pub time_in_power_zone: Vec<Option<f64>>,
+    /// Field def no: 69, `s`
+    pub avg_lap_time: Option<f64>,
+    /// Field def no: 70
+    pub best_lap_index: Option<u16>,
+    /// Field def no: 71, `m`
+    pub min_altitude: Option<f64>,
+    /// Field def no: 82
+    pub player_score: Option<u16>,
+    /// Field def no: 83
+    pub opponent_score: Option<u16>,
+    /// Field def no: 84
+    pub opponent_name: Option<String>,
+    /// Field def no: 85, `counts`, one value per element
+    pub stroke_count: Vec<Option<f64>>,
+    /// Field def no: 86, `counts`, one value per element
+    pub zone_count: Vec<Option<f64>>,
+    /// Field def no: 87, `m/s`
+    pub max_ball_speed: Option<f64>,
+    /// Field def no: 88, `m/s`
+    pub avg_ball_speed: Option<f64>,
+    /// Field def no: 89, `mm`
+    pub avg_vertical_oscillation: Option<f64>,
+    /// Field def no: 90, `percent`
+    pub avg_stance_time_percent: Option<f64>,
+    /// Field def no: 91, `ms`
+    pub avg_stance_time: Option<f64>,
+    /// Field def no: 92, `rpm`
+    pub avg_fractional_cadence: Option<f64>,
+    /// Field def no: 93, `rpm`
+    pub max_fractional_cadence: Option<f64>,
+    /// Field def no: 94, `cycles`
+    pub total_fractional_cycles: Option<f64>,
+    /// Field def no: 95, `g/dL`, one value per element
+    pub avg_total_hemoglobin_conc: Vec<Option<f64>>,
+    /// Field def no: 96, `g/dL`, one value per element
+    pub min_total_hemoglobin_conc: Vec<Option<f64>>,
+    /// Field def no: 97, `g/dL`, one value per element
+    pub max_total_hemoglobin_conc: Vec<Option<f64>>,
+    /// Field def no: 98, `%`, one value per element
+    pub avg_saturated_hemoglobin_percent: Vec<Option<f64>>,
+    /// Field def no: 99, `%`, one value per element
+    pub min_saturated_hemoglobin_percent: Vec<Option<f64>>,
+    /// Field def no: 100, `%`, one value per element
+    pub max_saturated_hemoglobin_percent: Vec<Option<f64>>,
+    /// Field def no: 101, `percent`
+    pub avg_left_torque_effectiveness: Option<f64>,
+    /// Field def no: 102, `percent`
+    pub avg_right_torque_effectiveness: Option<f64>,
+    /// Field def no: 103, `percent`
+    pub avg_left_pedal_smoothness: Option<f64>,
+    /// Field def no: 104, `percent`
+    pub avg_right_pedal_smoothness: Option<f64>,
+    /// Field def no: 105, `percent`
+    pub avg_combined_pedal_smoothness: Option<f64>,
+    /// Field def no: 111
+    pub sport_index: Option<u8>,
+    /// Field def no: 112, `s`
+    pub time_standing: Option<f64>,
+    /// Field def no: 113
+    pub stand_count: Option<u16>,
+    /// Field def no: 114, `mm`
+    pub avg_left_pco: Option<f64>,
+    /// Field def no: 115, `mm`
+    pub avg_right_pco: Option<f64>,
+    /// Field def no: 120, `watts`, one value per element
+    pub avg_power_position: Vec<Option<f64>>,
+    /// Field def no: 121, `watts`, one value per element
+    pub max_power_position: Vec<Option<f64>>,
+    /// Field def no: 122, `rpm`, one value per element
+    pub avg_cadence_position: Vec<Option<f64>>,
+    /// Field def no: 123, `rpm`, one value per element
+    pub max_cadence_position: Vec<Option<f64>>,
+    /// Field def no: 124, `m/s`
+    pub enhanced_avg_speed: Option<f64>,
+    /// Field def no: 125, `m/s`
+    pub enhanced_max_speed: Option<f64>,
+    /// Field def no: 126, `m`
+    pub enhanced_avg_altitude: Option<f64>,
+    /// Field def no: 127, `m`
+    pub enhanced_min_altitude: Option<f64>,
+    /// Field def no: 128, `m`
+    pub enhanced_max_altitude: Option<f64>,
+    /// Field def no: 129, `watts`
+    pub avg_lev_motor_power: Option<f64>,
+    /// Field def no: 130, `watts`
+    pub max_lev_motor_power: Option<f64>,
+    /// Field def no: 131, `percent`
+    pub lev_battery_consumption: Option<f64>,
+    /// Field def no: 132, `percent`
+    pub avg_vertical_ratio: Option<f64>,
+    /// Field def no: 133, `percent`
+    pub avg_stance_time_balance: Option<f64>,
+    /// Field def no: 134, `mm`
+    pub avg_step_length: Option<f64>,
+    /// Field def no: 137
+    pub total_anaerobic_training_effect: Option<f64>,
+    /// Field def no: 139, `m/s`
+    pub avg_vam: Option<f64>,
+    /// Field def no: 181, `kGrit`
+    pub total_grit: Option<f64>,
+    /// Field def no: 182, `Flow`
+    pub total_flow: Option<f64>,
+    /// Field def no: 183
+    pub jump_count: Option<u16>,
+    /// Field def no: 186, `kGrit`
+    pub avg_grit: Option<f64>,
+    /// Field def no: 187, `Flow`
+    pub avg_flow: Option<f64>,
+    /// Field def no: 199, `m`
+    pub total_fractional_ascent: Option<f64>,
+    /// Field def no: 200, `m`
+    pub total_fractional_descent: Option<f64>,
+    /// Field def no: 253, FIT timestamp in seconds
+    pub timestamp: Option<u32>,
+    /// Field def no: 254
+    pub message_index: Option<u16>,
+    pub(crate) index: usize,
+}
+
+impl Session {
+    /// Parse `DataMessage` as `Session` (`session/18`).
+    /// Fields that are not logged, or only contain invalid values,
+    /// are `None`.
+    pub fn new(data_message: &DataMessage) -> Result<Self, FitError> {
+        let global_id = 18_u16; // session
+
+        if data_message.global != global_id {
+            return Err(FitError::UnexpectedMessageType{expected: global_id, got: data_message.global})
+        }
+
+        let mut session = Self {
+            index: data_message.index,
+            ..Self::default()
+        };
+
+        for field in data_message.fields.iter() {
+            match field.field_def_no() {
+                0 => session.event = field.value_as().and_then(Event::from_u8),
+                1 => session.event_type = field.value_as().and_then(EventType::from_u8),
+                2 => session.start_time = field.value_as(),
+                3 => session.start_position_lat = field.profile_value_f64(global_id),
+                4 => session.start_position_long = field.profile_value_f64(global_id),
+                5 => session.sport = field.value_as().and_then(Sport::from_u8),
+                6 => session.sub_sport = field.value_as().and_then(SubSport::from_u8),
+                7 => session.total_elapsed_time = field.profile_value_f64(global_id),
+                8 => session.total_timer_time = field.profile_value_f64(global_id),
+                9 => session.total_distance = field.profile_value_f64(global_id),
+                10 => session.total_cycles = field.profile_value_f64(global_id),
+                11 => session.total_calories = field.profile_value_f64(global_id),
+                13 => session.total_fat_calories = field.profile_value_f64(global_id),
+                14 => session.avg_speed = field.profile_value_f64(global_id),
+                15 => session.max_speed = field.profile_value_f64(global_id),
+                16 => session.avg_heart_rate = field.profile_value_f64(global_id),
+                17 => session.max_heart_rate = field.profile_value_f64(global_id),
+                18 => session.avg_cadence = field.profile_value_f64(global_id),
+                19 => session.max_cadence = field.profile_value_f64(global_id),
+                20 => session.avg_power = field.profile_value_f64(global_id),
+                21 => session.max_power = field.profile_value_f64(global_id),
+                22 => session.total_ascent = field.profile_value_f64(global_id),
+                23 => session.total_descent = field.profile_value_f64(global_id),
+                24 => session.total_training_effect = field.profile_value_f64(global_id),
+                25 => session.first_lap_index = field.value_as(),
+                26 => session.num_laps = field.value_as(),
+                27 => session.event_group = field.value_as(),
+                28 => session.trigger = field.value_as(),
+                29 => session.nec_lat = field.profile_value_f64(global_id),
+                30 => session.nec_long = field.profile_value_f64(global_id),
+                31 => session.swc_lat = field.profile_value_f64(global_id),
+                32 => session.swc_long = field.profile_value_f64(global_id),
+                33 => session.num_lengths = field.profile_value_f64(global_id),
+                34 => session.normalized_power = field.profile_value_f64(global_id),
+                35 => session.training_stress_score = field.profile_value_f64(global_id),
+                36 => session.intensity_factor = field.profile_value_f64(global_id),
+                37 => session.left_right_balance = field.value_as(),
+                41 => session.avg_stroke_count = field.profile_value_f64(global_id),
+                42 => session.avg_stroke_distance = field.profile_value_f64(global_id),
+                43 => session.swim_stroke = field.value_as(),
+                44 => session.pool_length = field.profile_value_f64(global_id),
+                45 => session.threshold_power = field.profile_value_f64(global_id),
+                46 => session.pool_length_unit = field.value_as(),
+                47 => session.num_active_lengths = field.profile_value_f64(global_id),
+                48 => session.total_work = field.profile_value_f64(global_id),
+                49 => session.avg_altitude = field.profile_value_f64(global_id),
+                50 => session.max_altitude = field.profile_value_f64(global_id),
+                51 => session.gps_accuracy = field.profile_value_f64(global_id),
+                52 => session.avg_grade = field.profile_value_f64(global_id),
+                53 => session.avg_pos_grade = field.profile_value_f64(global_id),
+                54 => session.avg_neg_grade = field.profile_value_f64(global_id),
+                55 => session.max_pos_grade = field.profile_value_f64(global_id),
+                56 => session.max_neg_grade = field.profile_value_f64(global_id),
+                57 => session.avg_temperature = field.profile_value_f64(global_id),
+                58 => session.max_temperature = field.profile_value_f64(global_id),
+                59 => session.total_moving_time = field.profile_value_f64(global_id),
+                60 => session.avg_pos_vertical_speed = field.profile_value_f64(global_id),
+                61 => session.avg_neg_vertical_speed = field.profile_value_f64(global_id),
+                62 => session.max_pos_vertical_speed = field.profile_value_f64(global_id),
+                63 => session.max_neg_vertical_speed = field.profile_value_f64(global_id),
+                64 => session.min_heart_rate = field.profile_value_f64(global_id),
+                65 => session.time_in_hr_zone = field.profile_values_f64(global_id),
+                66 => session.time_in_speed_zone = field.profile_values_f64(global_id),
+                67 => session.time_in_cadence_zone = field.profile_values_f64(global_id),
+                68 => session.time_in_power_zone = field.profile_values_f64(global_id),
+                69 => session.avg_lap_time = field.profile_value_f64(global_id),
+                70 => session.best_lap_index = field.value_as(),
+                71 => session.min_altitude = field.profile_value_f64(global_id),
+                82 => session.player_score = field.value_as(),
+                83 => session.opponent_score = field.value_as(),
+                84 => session.opponent_name = field.data.as_ref().into(),
+                85 => session.stroke_count = field.profile_values_f64(global_id),
+                86 => session.zone_count = field.profile_values_f64(global_id),
+                87 => session.max_ball_speed = field.profile_value_f64(global_id),
+                88 => session.avg_ball_speed = field.profile_value_f64(global_id),
+                89 => session.avg_vertical_oscillation = field.profile_value_f64(global_id),
+                90 => session.avg_stance_time_percent = field.profile_value_f64(global_id),
+                91 => session.avg_stance_time = field.profile_value_f64(global_id),
+                92 => session.avg_fractional_cadence = field.profile_value_f64(global_id),
+                93 => session.max_fractional_cadence = field.profile_value_f64(global_id),
+                94 => session.total_fractional_cycles = field.profile_value_f64(global_id),
+                95 => session.avg_total_hemoglobin_conc = field.profile_values_f64(global_id),
+                96 => session.min_total_hemoglobin_conc = field.profile_values_f64(global_id),
+                97 => session.max_total_hemoglobin_conc = field.profile_values_f64(global_id),
+                98 => session.avg_saturated_hemoglobin_percent = field.profile_values_f64(global_id),
+                99 => session.min_saturated_hemoglobin_percent = field.profile_values_f64(global_id),
+                100 => session.max_saturated_hemoglobin_percent = field.profile_values_f64(global_id),
+                101 => session.avg_left_torque_effectiveness = field.profile_value_f64(global_id),
+                102 => session.avg_right_torque_effectiveness = field.profile_value_f64(global_id),
+                103 => session.avg_left_pedal_smoothness = field.profile_value_f64(global_id),
+                104 => session.avg_right_pedal_smoothness = field.profile_value_f64(global_id),
+                105 => session.avg_combined_pedal_smoothness = field.profile_value_f64(global_id),
+                111 => session.sport_index = field.value_as(),
+                112 => session.time_standing = field.profile_value_f64(global_id),
+                113 => session.stand_count = field.value_as(),
+                114 => session.avg_left_pco = field.profile_value_f64(global_id),
+                115 => session.avg_right_pco = field.profile_value_f64(global_id),
+                120 => session.avg_power_position = field.profile_values_f64(global_id),
+                121 => session.max_power_position = field.profile_values_f64(global_id),
+                122 => session.avg_cadence_position = field.profile_values_f64(global_id),
+                123 => session.max_cadence_position = field.profile_values_f64(global_id),
+                124 => session.enhanced_avg_speed = field.profile_value_f64(global_id),
+                125 => session.enhanced_max_speed = field.profile_value_f64(global_id),
+                126 => session.enhanced_avg_altitude = field.profile_value_f64(global_id),
+                127 => session.enhanced_min_altitude = field.profile_value_f64(global_id),
+                128 => session.enhanced_max_altitude = field.profile_value_f64(global_id),
+                129 => session.avg_lev_motor_power = field.profile_value_f64(global_id),
+                130 => session.max_lev_motor_power = field.profile_value_f64(global_id),
+                131 => session.lev_battery_consumption = field.profile_value_f64(global_id),
+                132 => session.avg_vertical_ratio = field.profile_value_f64(global_id),
+                133 => session.avg_stance_time_balance = field.profile_value_f64(global_id),
+                134 => session.avg_step_length = field.profile_value_f64(global_id),
+                137 => session.total_anaerobic_training_effect = field.profile_value_f64(global_id),
+                139 => session.avg_vam = field.profile_value_f64(global_id),
+                181 => session.total_grit = field.profile_value_f64(global_id),
+                182 => session.total_flow = field.profile_value_f64(global_id),
+                183 => session.jump_count = field.value_as(),
+                186 => session.avg_grit = field.profile_value_f64(global_id),
+                187 => session.avg_flow = field.profile_value_f64(global_id),
+                199 => session.total_fractional_ascent = field.profile_value_f64(global_id),
+                200 => session.total_fractional_descent = field.profile_value_f64(global_id),
+                253 => session.timestamp = field.value_as(),
+                254 => session.message_index = field.value_as(),
+                _ => (),
+            }
+        }
+
+        Ok(session)
+    }
+
+    /// Extract all `Session` (`session/18`) from FIT, in logging order.
+    pub fn from_fit(
+        fit: &Fit,
+        range: Option<&Range<usize>>, // slice indeces for session
+    ) -> Result<Vec<Self>, FitError> {
+        let global = 18_u16;
+
+        let range = range.cloned().unwrap_or(0 .. fit.len());
+
+        fit.records[range].par_iter()
+            .filter(|rec| rec.global == global)
+            .map(Self::new)
+            .collect()
+    }
+}
@@ -0,0 +1,307 @@
+//! Piecewise-linear time model for converting relative timestamps,
+//! as logged by Garmin VIRB, to absolute UTC time.
+//!
+//! VIRB logs timestamps relative to the start of the FIT file,
+//! and correlates these with UTC time in `timestamp_correlation/162`.
+//! The system clock drifts against GPS time, which is measurable
+//! for long recordings, so a single offset for the whole file,
+//! as in `Fit::t0()`, is not exact.
+//! `TimeModel` interpolates between all correlation points instead,
+//! optionally including `gps_metadata/160` UTC timestamps.
+
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use time::{Duration, OffsetDateTime};
+
+use crate::{
+    constants::FIT_DEFAULT_DATETIME,
+    Fit,
+    FitError,
+    GpsMetadata,
+    TimestampCorrelation,
+};
+
+/// Minimum interval in milliseconds between
+/// time points derived from `gps_metadata/160`.
+const GPS_INTERVAL_MS: i64 = 60_000;
+
+/// Source for a `TimePoint`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TimePointSource {
+    /// `timestamp_correlation/162`.
+    TimestampCorrelation,
+    /// `utc_timestamp` in `gps_metadata/160`.
+    /// Only has second resolution, and is logged at 10Hz,
+    /// so UTC time may lag by up to 100ms.
+    GpsMetadata,
+}
+
+/// Relative system time correlated with absolute UTC time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimePoint {
+    /// System time in milliseconds, relative to the start of the FIT file.
+    pub system_ms: i64,
+    /// UTC time in milliseconds since
+    /// FIT base start time 1989-12-31T00:00:00.000.
+    pub utc_ms: i64,
+    pub source: TimePointSource,
+}
+
+impl TimePoint {
+    /// Offset in milliseconds between UTC and system time.
+    pub fn offset_ms(&self) -> i64 {
+        self.utc_ms - self.system_ms
+    }
+}
+
+/// Clock drift at a `TimePoint`, i.e. the change in offset between
+/// UTC and system time since the first time point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimeDrift {
+    /// System time in milliseconds, relative to the start of the FIT file.
+    pub system_ms: i64,
+    /// Drift in milliseconds. Positive if the system clock runs slow.
+    pub drift_ms: i64,
+}
+
+/// Piecewise-linear model for converting relative system time
+/// to absolute UTC time. Interpolates between time points,
+/// and extrapolates from the first and last segments.
+/// A single time point means a constant offset.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimeModel {
+    /// Time points, sorted on system time.
+    points: Vec<TimePoint>,
+}
+
+impl TimeModel {
+    /// New time model. Time points are sorted on system time,
+    /// and only the first is kept for identical system times.
+    pub fn new(mut points: Vec<TimePoint>) -> Self {
+        points.sort_by_key(|p| p.system_ms);
+        points.dedup_by_key(|p| p.system_ms);
+        Self {
+            points
+        }
+    }
+
+    /// VIRB only.
+    ///
+    /// Time model from all `timestamp_correlation/162` in `fit`,
+    /// and, if `gps` is set, from `gps_metadata/160` UTC timestamps,
+    /// at most one per minute.
+    ///
+    /// Returns `FitError::ErrorParsingMessage(162)` if no time points
+    /// could be derived.
+    pub fn from_fit(fit: &Fit, gps: bool) -> Result<Self, FitError> {
+        let mut points: Vec<TimePoint> = TimestampCorrelation::from_fit_all(fit)?
+            .iter()
+            .map(|tc| TimePoint {
+                system_ms: tc.system_ms(),
+                utc_ms: tc.utc_ms(),
+                source: TimePointSource::TimestampCorrelation,
+            })
+            .collect();
+
+        if gps {
+            points.extend(Self::gps_points(fit));
+        }
+
+        if points.is_empty() {
+            return Err(FitError::ErrorParsingMessage(162))
+        }
+
+        Ok(Self::new(points))
+    }
+
+    /// Time points from `gps_metadata/160`, where `utc_timestamp`
+    /// changes, i.e. close to the start of a UTC second,
+    /// at most one per `GPS_INTERVAL_MS`.
+    /// Messages with invalid values are skipped.
+    fn gps_points(fit: &Fit) -> Vec<TimePoint> {
+        // (system time in ms, UTC timestamp in s)
+        let gps: Vec<(i64, u32)> = fit.records.par_iter()
+            .filter(|rec| rec.global == 160)
+            .filter_map(|rec| GpsMetadata::new(rec).ok())
+            .filter_map(|gps| match (gps.timestamp_ms, gps.utc_timestamp) {
+                (Some(ms), Some(utc)) => Some((gps.timestamp as i64 * 1000 + ms as i64, utc)),
+                _ => None
+            })
+            .collect();
+
+        let mut points: Vec<TimePoint> = Vec::new();
+        for pair in gps.windows(2) {
+            if pair[0].1 == pair[1].1 {
+                continue
+            }
+            let point = TimePoint {
+                system_ms: pair[1].0,
+                utc_ms: pair[1].1 as i64 * 1000,
+                source: TimePointSource::GpsMetadata,
+            };
+            match points.last() {
+                Some(last) if point.system_ms - last.system_ms < GPS_INTERVAL_MS => (),
+                _ => points.push(point),
+            }
+        }
+        points
+    }
+
+    /// Time points, sorted on system time.
+    pub fn points(&self) -> &[TimePoint] {
+        &self.points
+    }
+
+    /// Returns `true` if there are no time points.
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Converts system time in milliseconds to UTC time in milliseconds
+    /// since FIT base start time 1989-12-31T00:00:00.000.
+    /// Returns `None` if there are no time points.
+    pub fn utc_ms(&self, system_ms: i64) -> Option<i64> {
+        let (a, b) = match self.points.len() {
+            0 => return None,
+            1 => return Some(system_ms + self.points[0].offset_ms()),
+            len => {
+                // Segment containing `system_ms`,
+                // or first/last segment for extrapolation
+                let i = self.points.partition_point(|p| p.system_ms <= system_ms)
+                    .clamp(1, len - 1);
+                (&self.points[i - 1], &self.points[i])
+            }
+        };
+        let slope = (b.utc_ms - a.utc_ms) as f64 / (b.system_ms - a.system_ms) as f64;
+        Some(a.utc_ms + ((system_ms - a.system_ms) as f64 * slope).round() as i64)
+    }
+
+    /// Converts system time in milliseconds to absolute UTC time.
+    /// Returns `None` if there are no time points.
+    pub fn datetime(&self, system_ms: i64) -> Option<OffsetDateTime> {
+        self.utc_ms(system_ms)
+            .map(|ms| (FIT_DEFAULT_DATETIME + Duration::milliseconds(ms)).assume_utc())
+    }
+
+    /// Clock drift at each time point,
+    /// relative to the first time point.
+    /// This is the error a single offset for the whole file,
+    /// as in `Fit::t0()`, would have at each time point.
+    pub fn drift(&self) -> Vec<TimeDrift> {
+        let first = match self.points.first() {
+            Some(p) => p.offset_ms(),
+            None => return Vec::new()
+        };
+        self.points.iter()
+            .map(|p| TimeDrift {
+                system_ms: p.system_ms,
+                drift_ms: p.offset_ms() - first,
+            })
+            .collect()
+    }
+
+    /// Largest absolute clock drift in milliseconds,
+    /// see `TimeModel::drift()`.
+    pub fn max_drift_ms(&self) -> i64 {
+        self.drift().iter()
+            .map(|d| d.drift_ms.abs())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Average clock drift rate between the first and last time points,
+    /// in parts per million. Returns `None` for fewer than two time points.
+    pub fn drift_ppm(&self) -> Option<f64> {
+        let (first, last) = match (self.points.first(), self.points.last()) {
+            (Some(f), Some(l)) if l.system_ms > f.system_ms => (f, l),
+            _ => return None
+        };
+        Some((last.offset_ms() - first.offset_ms()) as f64
+            / (last.system_ms - first.system_ms) as f64 * 1_000_000.)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(system_ms: i64, utc_ms: i64) -> TimePoint {
+        TimePoint {
+            system_ms,
+            utc_ms,
+            source: TimePointSource::TimestampCorrelation,
+        }
+    }
+
+    /// Hourly time points, drifting 36 ms the first hour
+    /// and 18 ms the second.
+    fn model() -> TimeModel {
+        TimeModel::new(vec![
+            point(7_200_000, 8_200_054),
+            point(0, 1_000_000),
+            point(3_600_000, 4_600_036),
+            // Same system time, only the first is kept
+            point(3_600_000, 4_700_000),
+        ])
+    }
+
+    #[test]
+    fn time_model_sorted() {
+        let systems = model().points().iter()
+            .map(|p| p.system_ms)
+            .collect::<Vec<_>>();
+        assert_eq!(systems, [0, 3_600_000, 7_200_000]);
+    }
+
+    #[test]
+    fn time_model_interpolation() {
+        let model = model();
+        assert_eq!(model.utc_ms(0), Some(1_000_000));
+        assert_eq!(model.utc_ms(1_800_000), Some(2_800_018));
+        assert_eq!(model.utc_ms(3_600_000), Some(4_600_036));
+        assert_eq!(model.utc_ms(5_400_000), Some(6_400_045));
+        // Extrapolated from the first and last segments
+        assert_eq!(model.utc_ms(-3_600_000), Some(-2_600_036));
+        assert_eq!(model.utc_ms(10_800_000), Some(11_800_072));
+
+        assert_eq!(
+            model.datetime(0),
+            Some((FIT_DEFAULT_DATETIME + Duration::milliseconds(1_000_000)).assume_utc())
+        );
+    }
+
+    #[test]
+    fn time_model_constant_offset() {
+        let model = TimeModel::new(vec![point(1_000, 5_000)]);
+        assert_eq!(model.utc_ms(0), Some(4_000));
+        assert_eq!(model.utc_ms(10_000), Some(14_000));
+        assert_eq!(model.drift_ppm(), None);
+    }
+
+    #[test]
+    fn time_model_drift() {
+        let model = model();
+        let drift = model.drift().iter()
+            .map(|d| (d.system_ms, d.drift_ms))
+            .collect::<Vec<_>>();
+        assert_eq!(drift, [(0, 0), (3_600_000, 36), (7_200_000, 54)]);
+        assert_eq!(model.max_drift_ms(), 54);
+
+        // 54 ms over 2 hours
+        let ppm = model.drift_ppm().unwrap();
+        assert!((ppm - 7.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn time_model_empty() {
+        let model = TimeModel::default();
+        assert!(model.is_empty());
+        assert_eq!(model.utc_ms(0), None);
+        assert!(model.drift().is_empty());
+        assert_eq!(model.max_drift_ms(), 0);
+        assert_eq!(model.drift_ppm(), None);
+    }
+}
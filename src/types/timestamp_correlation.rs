@@ -16,6 +16,7 @@ use crate::{
 /// Important: presumably logged at satellite sync (?),
 /// but does NOT always precede the first gps_metadata (160) message
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TimestampCorrelation {
     pub timestamp: u32,    // seconds
     pub timestamp_ms: u16, // milliseconds
@@ -81,4 +82,34 @@ impl TimestampCorrelation {
             .map(Self::new) // how to forward error for single item, a la '.collect::<Result<_>>()?'
             .ok_or_else(|| FitError::ErrorParsingMessage(global))?
     }
+
+    /// VIRB only.
+    ///
+    /// Parses all `timestamp_correlation/162` in logging order.
+    /// Long recordings may log several, which allows
+    /// for correcting system clock drift, see `TimeModel`.
+    ///
+    /// If FIT contains no timestamp_correlation/162,
+    /// an empty vec will be returned with no errors
+    pub fn from_fit_all(
+        fit: &Fit,
+    ) -> Result<Vec<Self>, FitError> {
+        let global = 162_u16;
+
+        fit.records.par_iter()
+            .filter(|rec| rec.global == global)
+            .map(Self::new)
+            .collect()
+    }
+
+    /// System time in milliseconds, relative to the start of the FIT file.
+    pub fn system_ms(&self) -> i64 {
+        self.system_timestamp as i64 * 1000 + self.system_timestamp_ms as i64
+    }
+
+    /// UTC time in milliseconds since
+    /// FIT base start time 1989-12-31T00:00:00.000.
+    pub fn utc_ms(&self) -> i64 {
+        self.timestamp as i64 * 1000 + self.timestamp_ms as i64
+    }
 }
@@ -3,9 +3,9 @@
 
 use std::{ops::Range, path::{PathBuf, Path}};
 
-use time::{Duration, PrimitiveDateTime};
+use time::{Duration, PrimitiveDateTime, UtcOffset};
 
-use crate::{Fit, FitError, CameraEvent};
+use crate::{Fit, FitError, FitFilter, CameraEvent, CameraEventType};
 
 /// FIT data corresponding to VIRB recording session
 /// via MP4 clip UUIDs translated to index range in `Fit.records`.
@@ -65,11 +65,11 @@ impl FitSession {
                 // always be 0 and never e.g. 2 (session end).
 
                 if self.uuid.contains(&evt.camera_file_uuid) {
-                    match evt.camera_event_type {
+                    match evt.event_type() {
                         // 0 = recording session start
-                        0 => self.start = evt.index,
+                        Some(CameraEventType::VideoStart) => self.start = evt.index,
                         // 2 = recording session end
-                        2 => self.end = evt.index,
+                        Some(CameraEventType::VideoEnd) => self.end = evt.index,
                         // Ignore alternative camera events for session start/end
                         _ => (),
                     }
@@ -116,16 +116,31 @@ impl FitSession {
     /// Derives start, end date time for recording as tuple `(start, end)`.
     /// Use `FitSession.timespan_rel()` to derive relative
     /// timestamps.
+    ///
+    /// `offset` is the time offset (e.g. time zone) for start and end.
+    /// If `None`, the local time offset logged in the FIT file at start
+    /// and end respectively is used, so that a daylight saving time change
+    /// during the recording is accounted for. See `Fit::utc_offset_at()`.
+    /// Falls back to UTC if no local time offset is logged.
     /// 
     /// `default_on_error` sets start time for FIT-file to FIT base start time
     /// 1989-12-31T00:00:00.000 if no timestamp correlation value (FIT global 162)
     /// can be found.
-    pub fn timespan_abs(&self, offset_hrs: Option<i64>, default_on_error: bool) -> Result<(PrimitiveDateTime, PrimitiveDateTime), FitError> {
+    pub fn timespan_abs(&self, offset: Option<UtcOffset>, default_on_error: bool) -> Result<(PrimitiveDateTime, PrimitiveDateTime), FitError> {
         if let Some(fit) = &self.fit {
-            let t0 = fit.t0(offset_hrs.unwrap_or(0), default_on_error)?;
+            let t0 = fit.t0(UtcOffset::UTC, default_on_error)?;
 
             let (start_dur, end_dur) = self.timespan_rel().ok_or_else(|| FitError::NoSuchSession)?;
-            return Ok((t0 + start_dur, t0 + end_dur))
+
+            // UTC to local time
+            let local = |utc: PrimitiveDateTime| {
+                let offset = offset
+                    .or_else(|| fit.utc_offset_at(utc.assume_utc()))
+                    .unwrap_or(UtcOffset::UTC);
+                utc + Duration::seconds(offset.whole_seconds() as i64)
+            };
+
+            return Ok((local(t0 + start_dur), local(t0 + end_dur)))
         }
 
         Err(FitError::NoData)
@@ -137,7 +152,7 @@ impl FitSession {
 pub struct FitSessions {
     path: PathBuf,
     fit: Option<Fit>,
-    filtered: Option<u16>,
+    filtered: FitFilter,
     sessions: Vec<FitSession>
 }
 
@@ -167,19 +182,13 @@ impl FitSessions {
 
     /// Parses linked FIT-file.
     /// 
-    /// Optionally limit to specific message type via FIT global ID,
-    /// see Profile.xlsx in FIT SDK.
-    pub fn parse(&mut self, global: Option<u16>) -> Result<(), FitError> {
-        self.fit = match global {
-            Some(_) => {
-                self.filtered = global;
-                Some(Fit::parse(&self.path, global, false)?)
-            },
-            None => {
-                self.filtered = None;
-                Some(Fit::new(&self.path)?)
-            }
-        };
+    /// Optionally limit to specific message types via FIT global IDs,
+    /// see Profile.xlsx in FIT SDK, e.g. `Some(161)` or `[161, 162]`.
+    /// Use `None` to parse in full.
+    pub fn parse(&mut self, filter: impl Into<FitFilter>) -> Result<(), FitError> {
+        let filter = filter.into();
+        self.fit = Some(Fit::parse(&self.path, filter.to_owned(), false)?);
+        self.filtered = filter;
         Ok(())
     }
 
@@ -212,15 +221,15 @@ impl FitSessions {
                 // Logged chronologically so
                 // the very first encountered event should
                 // always be 0 and never e.g. 2 (session end).
-                match evt.camera_event_type {
+                match evt.event_type() {
                     // 0 = recording session start
-                    0 => {
+                    Some(CameraEventType::VideoStart) => {
                         session.path = self.path.to_owned();
                         session.start = evt.index;
                         session.uuid.push(evt.camera_file_uuid.to_owned());
                     },
                     // 2 = recording session end
-                    2 => {
+                    Some(CameraEventType::VideoEnd) => {
                         session.end = evt.index;
                         session.uuid.dedup(); // works without sort since logged chronologically
                         sessions.push(session);
@@ -228,7 +237,11 @@ impl FitSessions {
                     },
                     // Ignore alternative camera events for session start/end.
                     // Event type 3 is 'still photo taken' and has no relevance at all.
-                    3 | 4 | 6 => (),
+                    Some(
+                        CameraEventType::PhotoTaken
+                        | CameraEventType::VideoSecondStreamStart
+                        | CameraEventType::VideoSecondStreamEnd
+                    ) => (),
                     // Push UUID in between event types 0 and 2
                     // Duplicate UUIDs will always sit next to each other.
                     _ => session.uuid.push(evt.camera_file_uuid.to_owned())
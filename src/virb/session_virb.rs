@@ -2,7 +2,7 @@ use std::{collections::HashMap, hash::{DefaultHasher, Hash, Hasher}, ops::Range,
 
 use mp4iter::{Atom, Mp4Error};
 // use mp4iter::Udta;
-use time::{PrimitiveDateTime, Duration};
+use time::{PrimitiveDateTime, Duration, UtcOffset};
 use walkdir::WalkDir;
 
 use crate::{constants::FIT_DEFAULT_DATETIME, files::has_extension, types::FileId, Fit, FitError, GpsMetadata};
@@ -185,24 +185,24 @@ impl VirbSession {
         None
     }
 
-    fn get_from_index(sessions: &[Self], index: usize, offset_hours: Option<i64>) -> Result<Self, FitError> {
+    fn get_from_index(sessions: &[Self], index: usize, offset: Option<UtcOffset>) -> Result<Self, FitError> {
         if let Some(session) = sessions.get(index) {
             let mut session = session.to_owned();
             if !session.processed {
-                session.process(offset_hours.unwrap_or(0))?;
+                session.process(offset)?;
             }
             return Ok(session)
         }
         Err(FitError::NoSuchSession)
     }
 
-    fn get_from_uuid(sessions: &[Self], uuid: &str, offset_hours: Option<i64>) -> Result<Self, FitError> {
+    fn get_from_uuid(sessions: &[Self], uuid: &str, offset: Option<UtcOffset>) -> Result<Self, FitError> {
         // .contains() does not accept &str, only &String, see: https://github.com/rust-lang/rust/issues/42671
         // .find(|session| session.uuid().contains(uuid))
         if let Some(session) = sessions.iter().find(|session| session.uuid().iter().any(|u| u == uuid)) {
             let mut session = session.to_owned();
             if !session.processed {
-                session.process(offset_hours.unwrap_or(0))?;
+                session.process(offset)?;
             }
             return Ok(session)
         }
@@ -325,9 +325,12 @@ impl VirbSession {
         Ok(())
     }
 
-    fn set_time(&mut self, offset_hours: i64) -> Result<(), FitError> {
+    fn set_time(&mut self, offset: Option<UtcOffset>) -> Result<(), FitError> {
         if let Some(fit) = &self.fit.fit {
-            self.t0 = Some(fit.t0(offset_hours, false)?);
+            let offset = offset
+                .or_else(|| fit.utc_offset())
+                .unwrap_or(UtcOffset::UTC);
+            self.t0 = Some(fit.t0(offset, false)?);
 
             let cam = fit.camera(Some(&self.range()))?;
 
@@ -350,9 +353,12 @@ impl VirbSession {
     }
 
     /// Parses and processes matched FIT-file, then populates data, and time fields.
-    pub fn process(&mut self, offset_hours: i64) -> Result<(), FitError> {
+    /// `offset` is the time offset (e.g. time zone) for `VirbSession.t0`.
+    /// If `None`, the local time offset logged in the FIT file is used,
+    /// falling back to UTC. See `Fit::utc_offset()`.
+    pub fn process(&mut self, offset: Option<UtcOffset>) -> Result<(), FitError> {
         self.fit.derive()?;
-        self.set_time(offset_hours)?;
+        self.set_time(offset)?;
 
         Ok(())
     }
@@ -0,0 +1,73 @@
+use fit_rs::{DataMessage, DiagnosticKind, Fit, FitReader};
+
+/// `file_id`, then `record` messages with `cycles` 250, 5, 10,
+/// i.e. rolling over at 256, for `total_cycles` 250, 261, 266.
+const CYCLES: &str = "tests/data/cycles.fit";
+/// `file_id`, then a `record` message on local ID 1 before
+/// local ID 1 is defined, at byte offset 37.
+const UNDEFINED_LOCAL: &str = "tests/data/undefined_local.fit";
+
+/// Concatenates FIT files into a chained FIT file.
+fn chain(paths: &[&str]) -> Vec<u8> {
+    paths.iter()
+        .flat_map(|p| std::fs::read(p).unwrap())
+        .collect()
+}
+
+fn total_cycles(records: &[DataMessage]) -> Vec<Option<f64>> {
+    records.iter()
+        .filter(|r| r.global == 20)
+        .map(|r| r.fields.iter().find(|f| f.field_def_no() == 19).and_then(|f| f.value_f64()))
+        .collect()
+}
+
+#[test]
+fn chained_segments() {
+    let bytes = chain(&[CYCLES, CYCLES]);
+    let len = std::fs::read(CYCLES).unwrap().len() as u64;
+
+    let fit = Fit::from_reader(bytes.as_slice()).unwrap();
+    assert_eq!(fit.segments.len(), 2);
+    assert_eq!(fit.segments[0].offset, 0);
+    assert_eq!(fit.segments[1].offset, len);
+    assert_eq!(fit.segments[0].range(), 0 .. 4);
+    assert_eq!(fit.segments[1].range(), 4 .. 8);
+    assert!(fit.check_crc().is_ok());
+}
+
+#[test]
+fn chained_accumulators_reset() {
+    let bytes = chain(&[CYCLES, CYCLES]);
+    let fit = Fit::from_reader(bytes.as_slice()).unwrap();
+
+    // Accumulation starts over in the second FIT file,
+    // rather than continuing from 266 or rolling over from 10 to 250
+    let expected = [Some(250.), Some(261.), Some(266.)];
+    assert_eq!(total_cycles(&fit.records[fit.segments[0].range()]), expected);
+    assert_eq!(total_cycles(&fit.records[fit.segments[1].range()]), expected);
+}
+
+#[test]
+fn chained_definitions_reset() {
+    let bytes = chain(&[CYCLES, UNDEFINED_LOCAL]);
+    let len = std::fs::read(CYCLES).unwrap().len() as u64;
+
+    // Local ID 1 from the first FIT file does not carry over
+    let mut reader = FitReader::new(bytes.as_slice())
+        .with_lenient(true);
+    let records = reader.by_ref()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(reader.segments().len(), 2);
+
+    let diagnostics = reader.diagnostics();
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].kind, DiagnosticKind::UnknownDefinition);
+    assert_eq!(diagnostics[0].offset, len + 37);
+
+    let timestamps = records.iter()
+        .filter(|r| r.global == 20)
+        .filter_map(|r| r.timestamp())
+        .collect::<Vec<_>>();
+    assert_eq!(timestamps, [1000, 1001, 1002, 2001, 2002, 2003]);
+}
@@ -0,0 +1,38 @@
+use std::path::Path;
+
+use fit_rs::{Fit, FitReader};
+
+const FIXTURE: &str = "tests/data/compressed_timestamps.fit";
+
+/// `file_id`, one `record` with a full timestamp,
+/// then `record` messages with compressed timestamp headers,
+/// including rollovers at 1024 and 1056.
+const TIMESTAMPS: [u32; 7] = [1000, 1003, 1023, 1024, 1030, 1050, 1060];
+
+#[test]
+fn parse_compressed_timestamps() {
+    let fit = Fit::new(Path::new(FIXTURE)).unwrap();
+    assert!(fit.check_crc().is_ok());
+
+    let records = fit.filter(Some(20), None);
+    let timestamps = records.iter()
+        .map(|r| r.timestamp())
+        .collect::<Vec<_>>();
+    assert_eq!(timestamps, TIMESTAMPS.map(Some));
+
+    let heart_rates = records.iter()
+        .map(|r| r.fields.iter().find(|f| f.field_def_no() == 3).and_then(|f| f.value_f64()))
+        .collect::<Vec<_>>();
+    assert_eq!(heart_rates, (100 ..= 106).map(|n| Some(n as f64)).collect::<Vec<_>>());
+}
+
+#[test]
+fn stream_compressed_timestamps() {
+    let file = std::fs::File::open(FIXTURE).unwrap();
+    let timestamps = FitReader::new(file)
+        .map(|m| m.unwrap())
+        .filter(|m| m.global == 20)
+        .map(|m| m.timestamp())
+        .collect::<Vec<_>>();
+    assert_eq!(timestamps, TIMESTAMPS.map(Some));
+}
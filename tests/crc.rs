@@ -0,0 +1,53 @@
+use std::path::Path;
+
+use fit_rs::{CrcStatus, Fit, FitError};
+
+const FIXTURE: &str = "tests/data/compressed_timestamps.fit";
+/// 12 byte header, i.e. no header CRC.
+const HEADER_12: &str = "tests/data/header_12.fit";
+/// Ends in the middle of a message, i.e. no file CRC.
+const TRUNCATED: &str = "tests/data/lenient_truncated.fit";
+
+#[test]
+fn crc_valid() {
+    let fit = Fit::new_checked(Path::new(FIXTURE)).unwrap();
+
+    assert_eq!(fit.segments[0].crc.header, CrcStatus::Valid);
+    assert_eq!(fit.segments[0].crc.file, CrcStatus::Valid);
+}
+
+#[test]
+fn crc_file_mismatch() {
+    let mut bytes = std::fs::read(FIXTURE).unwrap();
+    // Last byte before the file CRC, i.e. a heart rate value
+    let i = bytes.len() - 3;
+    bytes[i] ^= 0x01;
+
+    let fit = Fit::from_reader(bytes.as_slice()).unwrap();
+    assert_eq!(fit.segments[0].crc.header, CrcStatus::Valid);
+    assert!(matches!(fit.segments[0].crc.file, CrcStatus::Mismatch{..}));
+    assert!(matches!(fit.check_crc(), Err(FitError::FileCrcMismatch{..})));
+}
+
+#[test]
+fn crc_header_mismatch() {
+    let mut bytes = std::fs::read(FIXTURE).unwrap();
+    // Profile version
+    bytes[2] ^= 0x01;
+
+    let fit = Fit::from_reader(bytes.as_slice()).unwrap();
+    assert!(matches!(fit.segments[0].crc.header, CrcStatus::Mismatch{..}));
+    assert!(matches!(fit.check_crc(), Err(FitError::HeaderCrcMismatch{..})));
+}
+
+#[test]
+fn crc_absent() {
+    let fit = Fit::new_checked(Path::new(HEADER_12)).unwrap();
+    assert_eq!(fit.segments[0].crc.header, CrcStatus::Absent);
+    assert_eq!(fit.segments[0].crc.file, CrcStatus::Valid);
+
+    let (fit, _) = Fit::parse_lenient(Path::new(TRUNCATED), None, false).unwrap();
+    assert_eq!(fit.segments[0].crc.header, CrcStatus::Valid);
+    assert_eq!(fit.segments[0].crc.file, CrcStatus::Absent);
+    assert!(fit.check_crc().is_ok());
+}
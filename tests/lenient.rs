@@ -0,0 +1,68 @@
+use std::path::Path;
+
+use fit_rs::{DiagnosticKind, Fit, FitReader};
+
+/// `file_id`, then `record` messages with timestamps 1000-1003,
+/// each 6 bytes, starting at byte offset 49.
+const TIMESTAMPS: [u32; 4] = [1000, 1001, 1002, 1003];
+
+/// Ends in the middle of the last `record`, without a file CRC.
+const TRUNCATED: &str = "tests/data/lenient_truncated.fit";
+/// 7 bytes of garbage at byte offset 61, between the second and third `record`.
+const GARBAGE: &str = "tests/data/lenient_garbage.fit";
+/// 5 byte data message at byte offset 61 for local ID 3,
+/// which has no definition, between the second and third `record`.
+const UNKNOWN_DEFINITION: &str = "tests/data/lenient_unknown_definition.fit";
+
+fn timestamps(fit: &Fit) -> Vec<u32> {
+    fit.filter(Some(20), None).iter()
+        .filter_map(|r| r.timestamp())
+        .collect()
+}
+
+#[test]
+fn lenient_truncated() {
+    assert!(Fit::new(Path::new(TRUNCATED)).is_err());
+
+    let (fit, diagnostics) = Fit::parse_lenient(Path::new(TRUNCATED), None, false).unwrap();
+    assert_eq!(timestamps(&fit), TIMESTAMPS[.. 3]);
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].kind, DiagnosticKind::Truncated);
+    assert_eq!(diagnostics[0].offset, 67);
+    assert_eq!(diagnostics[0].local, Some(1));
+}
+
+#[test]
+fn lenient_garbage() {
+    assert!(Fit::new(Path::new(GARBAGE)).is_err());
+
+    let (fit, diagnostics) = Fit::parse_lenient(Path::new(GARBAGE), None, false).unwrap();
+    assert_eq!(timestamps(&fit), TIMESTAMPS);
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].kind, DiagnosticKind::UnknownDefinition);
+    assert_eq!(diagnostics[0].offset, 61);
+    assert_eq!(diagnostics[0].local, Some(14));
+    assert_eq!(diagnostics[0].offset + 1 + diagnostics[0].skipped, 68);
+}
+
+#[test]
+fn lenient_unknown_definition() {
+    let bytes = std::fs::read(UNKNOWN_DEFINITION).unwrap();
+
+    let mut reader = FitReader::new(bytes.as_slice())
+        .with_lenient(true);
+    let timestamps = reader.by_ref()
+        .filter_map(|m| m.unwrap().timestamp())
+        .collect::<Vec<_>>();
+    assert_eq!(timestamps, TIMESTAMPS);
+
+    let diagnostics = reader.into_diagnostics();
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].kind, DiagnosticKind::UnknownDefinition);
+    assert_eq!(diagnostics[0].offset, 61);
+    assert_eq!(diagnostics[0].local, Some(3));
+    // Resumes at the third `record`, not at the file CRC
+    assert_eq!(diagnostics[0].offset + 1 + diagnostics[0].skipped, 66);
+}
@@ -0,0 +1,78 @@
+use std::path::Path;
+
+use fit_rs::{DataMessage, Fit, NativePrecedence, Value};
+
+/// `file_id`, `developer_data_id`, then `field_description` messages
+/// for developer fields that map to `record` fields, Stryd style:
+/// `Power` to `power` (7), `Speed` with scale 10 to `speed` (6),
+/// and `Lat` in semicircles to `position_lat` (0).
+/// Two `record` messages follow, the first with an invalid native `power`.
+const FIXTURE: &str = "tests/data/native_fields.fit";
+
+const APPLICATION_ID: [u8; 16] = [
+    0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17,
+    0x18, 0x19, 0x1A, 0x1B, 0x1C, 0x1D, 0x1E, 0x1F
+];
+
+fn records(precedence: NativePrecedence) -> Vec<DataMessage> {
+    let mut fit = Fit::new(Path::new(FIXTURE)).unwrap();
+    fit.resolve_native(precedence);
+    fit.filter(Some(20), None)
+}
+
+fn field(message: &DataMessage, field_def_no: u8) -> Option<f64> {
+    message.fields.iter()
+        .find(|f| f.field_def_no() == field_def_no)
+        .and_then(|f| f.value_f64())
+}
+
+#[test]
+fn native_power() {
+    let records = records(NativePrecedence::Native);
+
+    // Invalid native values are always replaced
+    assert_eq!(field(&records[0], 7), Some(250.));
+    assert_eq!(field(&records[1], 7), Some(300.));
+}
+
+#[test]
+fn developer_precedence() {
+    let records = records(NativePrecedence::Developer);
+
+    assert_eq!(field(&records[0], 7), Some(250.));
+    assert_eq!(field(&records[1], 7), Some(260.));
+}
+
+#[test]
+fn native_scale() {
+    let records = records(NativePrecedence::Native);
+
+    // 35 with scale 10 is 3.5 m/s, i.e. 3500 with the scale 1000 of `speed`
+    let speed = records[0].fields.iter().find(|f| f.field_def_no() == 6).unwrap();
+    assert!(matches!(&speed.data, Value::Uint16(v) if v == &[3500]));
+    assert_eq!(speed.value_f64(), Some(3.5));
+}
+
+#[test]
+fn native_semicircles() {
+    let records = records(NativePrecedence::Native);
+
+    // Raw semicircles are kept, not converted to degrees
+    let latitude = records[0].fields.iter().find(|f| f.field_def_no() == 0).unwrap();
+    assert!(matches!(&latitude.data, Value::Sint32(v) if v == &[123456789]));
+}
+
+#[test]
+fn native_provenance() {
+    let records = records(NativePrecedence::Developer);
+
+    let power = records[1].fields.iter().find(|f| f.field_def_no() == 7).unwrap();
+    assert_eq!(power.name(), Some("power"));
+    assert_eq!(power.developer_data_index(), Some(0));
+    assert_eq!(power.application_id(), Some(APPLICATION_ID));
+
+    // Native values that are kept have no provenance
+    let records = self::records(NativePrecedence::Native);
+    let power = records[1].fields.iter().find(|f| f.field_def_no() == 7).unwrap();
+    assert_eq!(power.developer_data_index(), None);
+}
@@ -0,0 +1,47 @@
+use std::path::Path;
+
+use fit_rs::{Fit, GpsMetadata, SensorData, SensorType};
+
+/// `file_id`, then `gps_metadata` and `gyroscope_data` messages,
+/// each valid first, then with fields filled with invalid value sentinels.
+const FIXTURE: &str = "tests/data/sentinels.fit";
+
+#[test]
+fn gps_metadata_sentinels() {
+    let fit = Fit::new(Path::new(FIXTURE)).unwrap();
+    let gps = GpsMetadata::from_fit(&fit, None).unwrap();
+
+    // The message with invalid coordinates is skipped
+    assert_eq!(gps.iter().map(|g| g.timestamp).collect::<Vec<_>>(), [1000, 1001]);
+
+    assert_eq!(gps[0].timestamp_ms, Some(100));
+    assert_eq!(gps[0].utc_timestamp, Some(1_000_000_000));
+    assert_eq!(gps[0].altitude, Some(2600));
+    assert_eq!(gps[0].velocity, Some(vec![10, 20, 30]));
+
+    assert_eq!(gps[1].timestamp_ms, None);
+    assert_eq!(gps[1].utc_timestamp, None);
+    assert_eq!(gps[1].altitude, None);
+    assert_eq!(gps[1].speed, None);
+    assert_eq!(gps[1].heading, None);
+    assert_eq!(gps[1].velocity, None);
+
+    let point = gps[1].to_point();
+    assert!((point.latitude - 59.5).abs() < 1e-6);
+    assert_eq!(point.altitude, 0.0);
+    assert_eq!(point.time.whole_milliseconds(), 1_001_000);
+}
+
+#[test]
+fn sensor_data_sentinels() {
+    let fit = Fit::new(Path::new(FIXTURE)).unwrap();
+    let gyro = SensorData::from_fit_uncalibrated(&fit, None, &SensorType::Gyroscope).unwrap();
+
+    // Messages with invalid samples, milliseconds,
+    // sample time offsets, or timestamps are skipped
+    assert_eq!(gyro.len(), 1);
+    assert_eq!(gyro[0].timestamp, 1000);
+    assert_eq!(gyro[0].sample_time_offset, [0, 10, 20]);
+    assert_eq!(gyro[0].x, [1, 2, 3]);
+    assert_eq!(gyro[0].z, [7, 8, 9]);
+}
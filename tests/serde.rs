@@ -0,0 +1,55 @@
+#![cfg(feature = "serde")]
+
+use fit_rs::Value;
+
+#[test]
+fn serde_value_layout() {
+    let value = Value::Uint16(vec![1, 65535]);
+    let json = serde_json::to_value(&value).unwrap();
+    assert_eq!(json, serde_json::json!({"type": "uint16", "values": [1, 65535]}));
+
+    let value = Value::String("VIRB".to_owned());
+    let json = serde_json::to_value(&value).unwrap();
+    assert_eq!(json, serde_json::json!({"type": "string", "values": "VIRB"}));
+
+    let value: Value = serde_json::from_str(r#"{"type": "sint8", "values": [-1, 2]}"#).unwrap();
+    assert!(matches!(value, Value::Sint8(v) if v == [-1, 2]));
+}
+
+#[test]
+fn serde_value_round_trip() {
+    let value = Value::Uint32z(vec![0, 7]);
+    let json = serde_json::to_string(&value).unwrap();
+    let value: Value = serde_json::from_str(&json).unwrap();
+    assert!(matches!(value, Value::Uint32z(v) if v == [0, 7]));
+}
+
+#[test]
+fn serde_value_nan() {
+    // Invalid float values, i.e. NaN, serialize as `null`
+    let value = Value::Float32(vec![1.5, f32::from_bits(u32::MAX)]);
+    let json = serde_json::to_string(&value).unwrap();
+    assert_eq!(json, r#"{"type":"float32","values":[1.5,null]}"#);
+
+    // ...and deserialize as the invalid sentinel
+    let value: Value = serde_json::from_str(&json).unwrap();
+    match value {
+        Value::Float32(values) => {
+            assert_eq!(values[0], 1.5);
+            assert_eq!(values[1].to_bits(), u32::MAX);
+        },
+        _ => panic!("expected float32"),
+    }
+
+    let value = Value::Float64(vec![f64::NAN, -2.25]);
+    let json = serde_json::to_string(&value).unwrap();
+    assert_eq!(json, r#"{"type":"float64","values":[null,-2.25]}"#);
+    let value: Value = serde_json::from_str(&json).unwrap();
+    match value {
+        Value::Float64(values) => {
+            assert!(values[0].is_nan());
+            assert_eq!(values[1], -2.25);
+        },
+        _ => panic!("expected float64"),
+    }
+}
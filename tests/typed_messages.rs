@@ -0,0 +1,211 @@
+use std::path::Path;
+
+use fit_rs::{
+    BatteryStatus,
+    Event,
+    EventType,
+    Fit,
+    FitError,
+    FitFile,
+    FileType,
+    SourceType,
+    Sport,
+    SubSport,
+    TimerTrigger,
+};
+
+/// Activity file: `file_id`, two `device_info` (creator and an ANT+ heart
+/// rate strap), `event` messages for timer start, battery, a rear gear
+/// change and timer stop, then `hrv`, `record`, `lap`, `session`, and `activity`.
+const FIXTURE: &str = "tests/data/activity.fit";
+/// Byte offset for `type` in `file_id/0`.
+const FILE_TYPE_OFFSET: usize = 36;
+
+fn fit() -> Fit {
+    Fit::new(Path::new(FIXTURE)).unwrap()
+}
+
+/// Returns the fixture with `type` in `file_id/0` set to `file_type`.
+fn fit_with_type(file_type: u8) -> Fit {
+    let mut bytes = std::fs::read(FIXTURE).unwrap();
+    bytes[FILE_TYPE_OFFSET] = file_type;
+    Fit::from_reader(bytes.as_slice()).unwrap()
+}
+
+#[test]
+fn typed_session() {
+    let sessions = fit().sessions_summary(None).unwrap();
+    assert_eq!(sessions.len(), 1);
+
+    let session = &sessions[0];
+    assert_eq!(session.start_time, Some(1000));
+    assert_eq!(session.sport, Some(Sport::Running));
+    assert_eq!(session.sub_sport, Some(SubSport::Treadmill));
+    assert_eq!(session.total_elapsed_time, Some(10.));
+    assert_eq!(session.total_distance, Some(50.));
+    assert_eq!(session.avg_heart_rate, Some(150.));
+    assert_eq!(session.first_lap_index, Some(0));
+    assert_eq!(session.num_laps, Some(1));
+    assert_eq!(session.time_in_hr_zone, [Some(1.), Some(2.), None]);
+    assert_eq!(session.timestamp, Some(1010));
+    // Not logged
+    assert_eq!(session.total_timer_time, None);
+}
+
+#[test]
+fn typed_lap() {
+    let laps = fit().laps(None).unwrap();
+    assert_eq!(laps.len(), 1);
+
+    let lap = &laps[0];
+    assert_eq!(lap.start_time, Some(1000));
+    // 2^30 semicircles
+    assert_eq!(lap.start_position_lat, Some(90.));
+    assert_eq!(lap.total_elapsed_time, Some(10.));
+    assert_eq!(lap.total_distance, Some(50.));
+    assert_eq!(lap.sport, Some(Sport::Running));
+    assert_eq!(lap.sub_sport, Some(SubSport::Treadmill));
+    assert_eq!(lap.end_position_lat, None);
+}
+
+#[test]
+fn typed_activity() {
+    let activity = fit().activity(None).unwrap();
+    assert_eq!(activity.total_timer_time, Some(10.));
+    assert_eq!(activity.num_sessions, Some(1));
+    assert_eq!(activity.event, Some(Event::Activity));
+    assert_eq!(activity.event_type, Some(EventType::Stop));
+    assert_eq!(activity.local_timestamp, Some(4610));
+    assert_eq!(activity.timestamp, Some(1010));
+}
+
+#[test]
+fn typed_event() {
+    let events = fit().events(None).unwrap();
+    assert_eq!(events.len(), 4);
+
+    assert!(events[0].is_timer_start());
+    assert_eq!(events[0].timer_trigger, Some(TimerTrigger::Manual));
+
+    assert_eq!(events[1].event, Some(Event::Battery));
+    assert_eq!(events[1].battery_level, Some(3.7));
+
+    // `data` decoded as rear/front gear number and teeth
+    assert_eq!(events[2].event, Some(Event::RearGearChange));
+    assert_eq!(events[2].rear_gear_num, Some(5));
+    assert_eq!(events[2].rear_gear, Some(28));
+    assert_eq!(events[2].front_gear_num, Some(2));
+    assert_eq!(events[2].front_gear, Some(50));
+
+    assert!(events[3].is_timer_stop());
+    assert_eq!(events[3].timestamp, Some(1010));
+}
+
+#[test]
+fn typed_device_info() {
+    let devices = fit().device_info(None).unwrap();
+    assert_eq!(devices.len(), 2);
+
+    let creator = &devices[0];
+    assert!(creator.is_creator());
+    assert_eq!(creator.manufacturer, Some(1));
+    assert_eq!(creator.product, Some(2697));
+    assert_eq!(creator.software_version, Some(12.34));
+    assert_eq!(creator.source_type, Some(SourceType::Local));
+    assert_eq!(creator.battery_voltage, None);
+
+    let strap = &devices[1];
+    assert!(!strap.is_creator());
+    assert_eq!(strap.serial_number, Some(5678));
+    assert_eq!(strap.battery_voltage, Some(3.));
+    assert_eq!(strap.battery_status, Some(BatteryStatus::Ok));
+    assert_eq!(strap.source_type, Some(SourceType::Antplus));
+    assert_eq!(strap.device_type_name(), Some("heart_rate"));
+    assert_eq!(strap.product_name.as_deref(), Some("HRM-Pro"));
+}
+
+#[test]
+fn typed_hrv() {
+    let hrv = fit().hrv(None).unwrap();
+    assert_eq!(hrv.len(), 1);
+    // Invalid padding values are excluded
+    assert_eq!(hrv[0].time, [0.8, 0.81]);
+}
+
+#[test]
+fn typed_wrong_message() {
+    let fit = fit();
+    let file_id = &fit.records[0];
+
+    assert!(matches!(
+        fit_rs::Session::new(file_id),
+        Err(FitError::UnexpectedMessageType{expected: 18, got: 0})
+    ));
+    assert!(matches!(
+        fit_rs::Lap::new(file_id),
+        Err(FitError::UnexpectedMessageType{expected: 19, got: 0})
+    ));
+    assert!(matches!(
+        fit_rs::Activity::new(file_id),
+        Err(FitError::UnexpectedMessageType{expected: 34, got: 0})
+    ));
+    assert!(matches!(
+        fit_rs::EventMessage::new(file_id),
+        Err(FitError::UnexpectedMessageType{expected: 21, got: 0})
+    ));
+    assert!(matches!(
+        fit_rs::DeviceInfo::new(file_id),
+        Err(FitError::UnexpectedMessageType{expected: 23, got: 0})
+    ));
+    assert!(matches!(
+        fit_rs::Hrv::new(file_id),
+        Err(FitError::UnexpectedMessageType{expected: 78, got: 0})
+    ));
+}
+
+#[test]
+fn file_activity() {
+    let fit = fit();
+    let activity = match fit.file().unwrap() {
+        FitFile::Activity(activity) => activity,
+        file => panic!("expected activity file, got {:?}", file.file_type()),
+    };
+    assert_eq!(activity.view().file_type(), Some(FileType::Activity));
+    assert_eq!(activity.view().file_id().unwrap().serial_number, 1234);
+
+    assert_eq!(activity.activity().unwrap().num_sessions, Some(1));
+    assert_eq!(activity.sessions().unwrap().len(), 1);
+    assert_eq!(activity.laps().unwrap().len(), 1);
+    assert_eq!(activity.records().len(), 1);
+    assert_eq!(activity.events().unwrap().len(), 4);
+    assert_eq!(activity.device_info().unwrap().len(), 2);
+    assert_eq!(activity.hrv().unwrap().len(), 1);
+}
+
+#[test]
+fn file_other_kinds() {
+    // `activity_summary`
+    assert!(matches!(fit_with_type(20).file().unwrap(), FitFile::Activity(_)));
+
+    // Same messages, but not an activity file
+    let fit = fit_with_type(6);
+    match fit.file().unwrap() {
+        FitFile::Course(course) => {
+            assert_eq!(course.laps().unwrap().len(), 1);
+            assert!(course.course().is_none());
+        },
+        file => panic!("expected course file, got {:?}", file.file_type()),
+    }
+    assert!(matches!(fit_with_type(5).file().unwrap(), FitFile::Workout(_)));
+    assert!(matches!(fit_with_type(9).file().unwrap(), FitFile::Weight(_)));
+
+    // `device`, and manufacturer specific types
+    for file_type in [1, 0xF7] {
+        let fit = fit_with_type(file_type);
+        match fit.file().unwrap() {
+            FitFile::Other(view) => assert_eq!(view.file_type_raw(), file_type),
+            file => panic!("expected other file, got {:?}", file.file_type()),
+        }
+    }
+}
+
@@ -0,0 +1,73 @@
+use std::path::Path;
+
+use fit_rs::{DataMessage, DeveloperDataId, Fit, FitReader, FitWriter};
+
+/// `file_id`, `developer_data_id`, `field_description`, then `record`
+/// messages with a developer field, from a big-endian definition
+/// and from a little-endian definition with compressed timestamp headers.
+const FIXTURE: &str = "tests/data/developer_fields.fit";
+
+fn power(message: &DataMessage) -> Option<f64> {
+    message.dev_fields.first().and_then(|f| f.value_f64())
+}
+
+#[test]
+fn round_trip_preserved_definitions() {
+    let bytes = std::fs::read(FIXTURE).unwrap();
+
+    let mut reader = FitReader::new(bytes.as_slice())
+        .with_expansion(false)
+        .with_definitions(true);
+    let records = reader.by_ref()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert!(reader.definitions().iter().any(|def| def.is_big_endian()));
+
+    let mut writer = FitWriter::new(Vec::new())
+        .with_header(reader.segments()[0].header)
+        .with_definitions(reader.definitions().to_vec());
+    writer.write_all(records.iter()).unwrap();
+
+    assert_eq!(writer.finish().unwrap(), bytes);
+}
+
+#[test]
+fn round_trip_fit() {
+    let bytes = std::fs::read(FIXTURE).unwrap();
+    let fit = Fit::new(Path::new(FIXTURE)).unwrap();
+
+    assert_eq!(fit.write_to(Vec::new()).unwrap(), bytes);
+}
+
+#[test]
+fn regenerated_developer_data_id() {
+    let fit = Fit::new(Path::new(FIXTURE)).unwrap();
+    let records = fit.filter(Some(20), None);
+
+    let mut writer = FitWriter::new(Vec::new())
+        .with_developer_data_ids(fit.developer_data_ids(None).unwrap());
+    writer.write_all(records.iter()).unwrap();
+    let bytes = writer.finish().unwrap();
+
+    let written = Fit::from_reader(bytes.as_slice()).unwrap();
+    written.check_crc().unwrap();
+
+    let ids = written.developer_data_ids(None).unwrap();
+    assert_eq!(ids.len(), 1);
+    let id: &DeveloperDataId = &ids[0];
+    assert_eq!(id.developer_id, Some(vec![1, 2, 3, 4]));
+    assert_eq!(id.application_id, Some(core::array::from_fn(|i| 0x10 + i as u8)));
+    assert_eq!(id.manufacturer_id, Some(255));
+    assert_eq!(id.developer_data_index, 0);
+    assert_eq!(id.application_version, Some(42));
+
+    let written_records = written.filter(Some(20), None);
+    assert_eq!(
+        written_records.iter().map(power).collect::<Vec<_>>(),
+        records.iter().map(power).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        written_records.iter().map(|r| r.timestamp()).collect::<Vec<_>>(),
+        [1000, 1003, 1020, 1030, 1040].map(Some)
+    );
+}